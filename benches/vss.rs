@@ -0,0 +1,77 @@
+use std::time::{Duration, Instant};
+
+use noist::{
+    secret::secret_share_gen,
+    share::SecretShare,
+    vss::{vss_verify_secret, vss_verify_secrets},
+};
+use secp::{MaybePoint, Point, Scalar};
+
+fn time<F: FnMut()>(iterations: u32, mut f: F) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..iterations {
+        f();
+    }
+
+    start.elapsed() / iterations
+}
+
+// Baseline power, multiplying the base power times as before multi-scalar multiplication.
+fn baseline_pow_scalar(base: Scalar, power: u32) -> Scalar {
+    let mut result = match power {
+        0 => return Scalar::one(),
+        _ => base,
+    };
+
+    for _ in 0..(power - 1) {
+        result *= base;
+    }
+
+    result
+}
+
+// Baseline verification, summing A_j * i^j term by term as before multi-scalar multiplication.
+#[allow(non_snake_case)]
+fn naive_verify(share: &SecretShare, points: &[Point]) -> bool {
+    let i = share.index.to_scalar();
+
+    let mut P_i = MaybePoint::Infinity;
+
+    for (j, A_j) in points.iter().enumerate() {
+        P_i += *A_j * baseline_pow_scalar(i, j as u32);
+    }
+
+    match P_i {
+        MaybePoint::Infinity => false,
+        MaybePoint::Valid(point) => share.secret.expose().base_point_mul() == point,
+    }
+}
+
+fn main() {
+    for (n, t) in [(10, 5), (100, 34), (300, 100)] {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, _, vss_commitments) = secret_share_gen(secret, n, t).unwrap();
+
+        let naive = time(3, || {
+            for share in secret_shares.iter() {
+                assert!(naive_verify(share, vss_commitments.points()));
+            }
+        });
+
+        let msm = time(3, || {
+            for share in secret_shares.iter() {
+                assert!(vss_verify_secret(share, &vss_commitments));
+            }
+        });
+
+        let batch = time(3, || {
+            vss_verify_secrets(&secret_shares, &vss_commitments).unwrap();
+        });
+
+        println!(
+            "n={:<4} t={:<4} naive {:>10.2?}  msm {:>10.2?}  batch {:>10.2?}",
+            n, t, naive, msm, batch
+        );
+    }
+}
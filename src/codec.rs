@@ -0,0 +1,658 @@
+use secp::{Point, Scalar};
+
+use crate::{
+    complaint::Complaint,
+    dkg::{DKGCommitment, KeyPackage},
+    error::NoistError,
+    nonce::{NonceCommitment, NonceDealing, NonceShare, SlotNonce},
+    pedersen::{PedersenCommitment, PedersenShare},
+    refresh::RefreshDealing,
+    repair::RepairContribution,
+    reshare::ReshareDealing,
+    sensitive::SecretScalar,
+    session::PartialSignature,
+    share::{ParticipantIndex, PublicShare, SecretShare},
+    transport::SealedPackage,
+    vse::{DleqProof, EncryptedShare},
+    vss::{vss_verify_secret, VssCommitment, VssProof},
+};
+
+/// Version byte leading every encoded object.
+pub const CODEC_VERSION: u8 = 1;
+
+/// Cursor over encoded bytes, tracking the offset for error reporting.
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> ByteReader<'a> {
+        ByteReader { bytes, offset: 0 }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], NoistError> {
+        if self.remaining() < len {
+            return Err(NoistError::InvalidLength {
+                expected: self.offset + len,
+                found: self.bytes.len(),
+            });
+        }
+
+        let bytes = &self.bytes[self.offset..self.offset + len];
+        self.offset += len;
+
+        Ok(bytes)
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], NoistError> {
+        let offset = self.offset;
+
+        self.read_bytes(N)?
+            .try_into()
+            .map_err(|_| NoistError::ParseError { offset })
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, NoistError> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, NoistError> {
+        Ok(u32::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, NoistError> {
+        Ok(u64::from_be_bytes(self.read_array()?))
+    }
+
+    /// Reads a u32 length prefix followed by that many bytes.
+    pub fn read_prefixed_bytes(&mut self) -> Result<&'a [u8], NoistError> {
+        let len = self.read_u32()? as usize;
+        self.read_bytes(len)
+    }
+
+    /// Reads a u32 count prefix followed by that many items.
+    pub fn read_vec<T: Codec>(&mut self) -> Result<Vec<T>, NoistError> {
+        let count = self.read_u32()?;
+
+        // Items are read one at a time so a forged count cannot force a large allocation.
+        let mut items = Vec::<T>::new();
+
+        for _ in 0..count {
+            items.push(T::decode_from(self)?);
+        }
+
+        Ok(items)
+    }
+}
+
+fn write_prefixed_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend((bytes.len() as u32).to_be_bytes());
+    out.extend(bytes);
+}
+
+/// Writes a u32 count prefix followed by the items, the counterpart of `ByteReader::read_vec`.
+pub fn write_vec<T: Codec>(items: &[T], out: &mut Vec<u8>) {
+    out.extend((items.len() as u32).to_be_bytes());
+
+    for item in items {
+        item.encode_to(out);
+    }
+}
+
+/// Versioned binary encoding of protocol objects.
+///
+/// Encoding is = version||tag||fields. Integers are big-endian, points are 33-byte compressed,
+/// scalars are 32 bytes and variable-length fields are prefixed with a u32 length.
+pub trait Codec: Sized {
+    /// Type tag following the version byte.
+    const TAG: u8;
+
+    /// Appends the fields, without version and tag.
+    fn encode_to(&self, out: &mut Vec<u8>);
+
+    /// Reads the fields, without version and tag.
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError>;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = vec![CODEC_VERSION, Self::TAG];
+        self.encode_to(&mut out);
+        out
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, NoistError> {
+        let mut reader = ByteReader::new(bytes);
+
+        let version = reader.read_u8()?;
+        if version != CODEC_VERSION {
+            return Err(NoistError::UnsupportedVersion(version));
+        }
+
+        if reader.read_u8()? != Self::TAG {
+            return Err(NoistError::ParseError { offset: 1 });
+        }
+
+        let item = Self::decode_from(&mut reader)?;
+
+        // Trailing bytes are rejected so every object has a single encoding.
+        match reader.remaining() {
+            0 => Ok(item),
+            _ => Err(NoistError::ParseError {
+                offset: reader.offset(),
+            }),
+        }
+    }
+}
+
+/// Batch of objects sent together, e.g. the nonce shares or repair contributions a dealer sends
+/// to one participant. Tag is the item tag with the high bit set.
+impl<T: Codec> Codec for Vec<T> {
+    const TAG: u8 = 0x80 | T::TAG;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        write_vec(self, out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        reader.read_vec()
+    }
+}
+
+impl Codec for ParticipantIndex {
+    const TAG: u8 = 0x01;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        out.extend(self.value().to_be_bytes());
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        ParticipantIndex::new(reader.read_u32()?)
+    }
+}
+
+impl Codec for Scalar {
+    const TAG: u8 = 0x02;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        out.extend(self.serialize());
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        let offset = reader.offset();
+
+        Scalar::from_slice(reader.read_bytes(32)?).map_err(|_| NoistError::ParseError { offset })
+    }
+}
+
+impl Codec for Point {
+    const TAG: u8 = 0x03;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        out.extend(self.serialize());
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        let offset = reader.offset();
+        let bytes = reader.read_bytes(33)?;
+
+        // Only compressed prefixes are accepted, so every point has a single encoding.
+        if bytes[0] != 0x02 && bytes[0] != 0x03 {
+            return Err(NoistError::ParseError { offset });
+        }
+
+        Point::from_slice(bytes).map_err(|_| NoistError::ParseError { offset })
+    }
+}
+
+impl Codec for SecretShare {
+    const TAG: u8 = 0x04;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.index.encode_to(out);
+        self.secret.expose().encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(SecretShare::new(
+            ParticipantIndex::decode_from(reader)?,
+            Scalar::decode_from(reader)?,
+        ))
+    }
+}
+
+impl Codec for PublicShare {
+    const TAG: u8 = 0x05;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.index.encode_to(out);
+        self.point.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(PublicShare::new(
+            ParticipantIndex::decode_from(reader)?,
+            Point::decode_from(reader)?,
+        ))
+    }
+}
+
+impl Codec for VssCommitment {
+    const TAG: u8 = 0x06;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        write_vec(self.points(), out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        VssCommitment::new(reader.read_vec()?)
+    }
+}
+
+impl Codec for PartialSignature {
+    const TAG: u8 = 0x07;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.index.encode_to(out);
+        self.sig.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(PartialSignature {
+            index: ParticipantIndex::decode_from(reader)?,
+            sig: Scalar::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for DKGCommitment {
+    const TAG: u8 = 0x08;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.index.encode_to(out);
+        self.vss_commitments.encode_to(out);
+        self.proof.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(DKGCommitment {
+            index: ParticipantIndex::decode_from(reader)?,
+            vss_commitments: VssCommitment::decode_from(reader)?,
+            proof: VssProof::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for KeyPackage {
+    const TAG: u8 = 0x09;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.secret_share.encode_to(out);
+        self.public_share.encode_to(out);
+        self.group_key.encode_to(out);
+        self.vss_commitments.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        let key_package = KeyPackage {
+            secret_share: SecretShare::decode_from(reader)?,
+            public_share: PublicShare::decode_from(reader)?,
+            group_key: Point::decode_from(reader)?,
+            vss_commitments: VssCommitment::decode_from(reader)?,
+        };
+
+        let index = key_package.secret_share.index;
+
+        if key_package.public_share.index != index {
+            return Err(NoistError::IndexMismatch {
+                expected: index,
+                found: key_package.public_share.index,
+            });
+        }
+
+        // Public share must be = s_i * G, and the share must lie on the commitments to the group key.
+        if key_package.public_share != key_package.secret_share.public_share()
+            || key_package.group_key != key_package.vss_commitments.constant()
+            || !vss_verify_secret(&key_package.secret_share, &key_package.vss_commitments)
+        {
+            return Err(NoistError::VssMismatch(index));
+        }
+
+        Ok(key_package)
+    }
+}
+
+impl Codec for NonceDealing {
+    const TAG: u8 = 0x0a;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.dealer.encode_to(out);
+        out.extend(self.start.to_be_bytes());
+        write_vec(&self.vss_commitments, out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(NonceDealing {
+            dealer: ParticipantIndex::decode_from(reader)?,
+            start: reader.read_u64()?,
+            vss_commitments: reader.read_vec()?,
+        })
+    }
+}
+
+impl Codec for RefreshDealing {
+    const TAG: u8 = 0x0b;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.dealer.encode_to(out);
+        self.vss_commitments.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(RefreshDealing {
+            dealer: ParticipantIndex::decode_from(reader)?,
+            vss_commitments: VssCommitment::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for ReshareDealing {
+    const TAG: u8 = 0x0c;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.dealer.encode_to(out);
+        self.vss_commitments.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(ReshareDealing {
+            dealer: ParticipantIndex::decode_from(reader)?,
+            vss_commitments: VssCommitment::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for SealedPackage {
+    const TAG: u8 = 0x0d;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.sender.encode_to(out);
+        self.recipient.encode_to(out);
+        out.extend(self.session_id);
+        out.extend(self.nonce);
+        write_prefixed_bytes(&self.ciphertext, out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(SealedPackage {
+            sender: ParticipantIndex::decode_from(reader)?,
+            recipient: ParticipantIndex::decode_from(reader)?,
+            session_id: reader.read_array()?,
+            nonce: reader.read_array()?,
+            ciphertext: reader.read_prefixed_bytes()?.to_vec(),
+        })
+    }
+}
+
+impl Codec for DleqProof {
+    const TAG: u8 = 0x0e;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.challenge.encode_to(out);
+        self.response.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(DleqProof {
+            challenge: Scalar::decode_from(reader)?,
+            response: Scalar::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for Complaint {
+    const TAG: u8 = 0x0f;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.accuser.encode_to(out);
+        self.accused.encode_to(out);
+        self.ecdh_point.encode_to(out);
+        self.proof.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(Complaint {
+            accuser: ParticipantIndex::decode_from(reader)?,
+            accused: ParticipantIndex::decode_from(reader)?,
+            ecdh_point: Point::decode_from(reader)?,
+            proof: DleqProof::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for RepairContribution {
+    const TAG: u8 = 0x10;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.from.encode_to(out);
+        self.to.encode_to(out);
+        self.value.expose().encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(RepairContribution {
+            from: ParticipantIndex::decode_from(reader)?,
+            to: ParticipantIndex::decode_from(reader)?,
+            value: SecretScalar::new(Scalar::decode_from(reader)?),
+        })
+    }
+}
+
+impl Codec for VssProof {
+    const TAG: u8 = 0x11;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.public_nonce.encode_to(out);
+        self.response.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(VssProof {
+            public_nonce: Point::decode_from(reader)?,
+            response: Scalar::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for PedersenCommitment {
+    const TAG: u8 = 0x12;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        write_vec(self.points(), out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        PedersenCommitment::new(reader.read_vec()?)
+    }
+}
+
+impl Codec for PedersenShare {
+    const TAG: u8 = 0x13;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.index.encode_to(out);
+        self.secret.expose().encode_to(out);
+        self.blinding.expose().encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(PedersenShare::new(
+            ParticipantIndex::decode_from(reader)?,
+            Scalar::decode_from(reader)?,
+            Scalar::decode_from(reader)?,
+        ))
+    }
+}
+
+impl Codec for EncryptedShare {
+    const TAG: u8 = 0x14;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.ephemeral_key.encode_to(out);
+        self.pad_commitment.encode_to(out);
+        self.encrypted_share.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(EncryptedShare {
+            ephemeral_key: Point::decode_from(reader)?,
+            pad_commitment: Point::decode_from(reader)?,
+            encrypted_share: Scalar::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for NonceCommitment {
+    const TAG: u8 = 0x15;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.hiding.encode_to(out);
+        self.binding.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(NonceCommitment {
+            hiding: VssCommitment::decode_from(reader)?,
+            binding: VssCommitment::decode_from(reader)?,
+        })
+    }
+}
+
+impl Codec for NonceShare {
+    const TAG: u8 = 0x16;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        self.hiding.encode_to(out);
+        self.binding.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        let nonce_share = NonceShare {
+            hiding: SecretShare::decode_from(reader)?,
+            binding: SecretShare::decode_from(reader)?,
+        };
+
+        // Both shares must belong to the same participant.
+        if nonce_share.binding.index != nonce_share.hiding.index {
+            return Err(NoistError::IndexMismatch {
+                expected: nonce_share.hiding.index,
+                found: nonce_share.binding.index,
+            });
+        }
+
+        Ok(nonce_share)
+    }
+}
+
+impl Codec for SlotNonce {
+    const TAG: u8 = 0x17;
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        out.extend(self.slot.to_be_bytes());
+        self.vss_commitments.encode_to(out);
+    }
+
+    fn decode_from(reader: &mut ByteReader) -> Result<Self, NoistError> {
+        Ok(SlotNonce {
+            slot: reader.read_u64()?,
+            vss_commitments: NonceCommitment::decode_from(reader)?,
+        })
+    }
+}
+
+/// Serde support through the binary encoding, as hex strings for human-readable formats.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use std::fmt;
+
+    use serde::{
+        de::{Error, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::*;
+
+    struct CodecVisitor<T>(std::marker::PhantomData<T>);
+
+    impl<'de, T: Codec> Visitor<'de> for CodecVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "encoded bytes or a hex string")
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<T, E> {
+            let bytes = hex::decode(value).map_err(E::custom)?;
+            T::from_bytes(&bytes).map_err(E::custom)
+        }
+
+        fn visit_bytes<E: Error>(self, value: &[u8]) -> Result<T, E> {
+            T::from_bytes(value).map_err(E::custom)
+        }
+    }
+
+    macro_rules! impl_serde {
+        ($($ty:ty),*) => {$(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    match serializer.is_human_readable() {
+                        true => serializer.serialize_str(&hex::encode(self.to_bytes())),
+                        false => serializer.serialize_bytes(&self.to_bytes()),
+                    }
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let visitor = CodecVisitor::<$ty>(std::marker::PhantomData);
+
+                    match deserializer.is_human_readable() {
+                        true => deserializer.deserialize_str(visitor),
+                        false => deserializer.deserialize_bytes(visitor),
+                    }
+                }
+            }
+        )*};
+    }
+
+    impl_serde!(
+        ParticipantIndex,
+        SecretShare,
+        PublicShare,
+        VssCommitment,
+        PartialSignature,
+        DKGCommitment,
+        KeyPackage,
+        NonceDealing,
+        RefreshDealing,
+        ReshareDealing,
+        SealedPackage,
+        DleqProof,
+        Complaint,
+        RepairContribution,
+        VssProof,
+        PedersenCommitment,
+        PedersenShare,
+        EncryptedShare,
+        NonceCommitment,
+        NonceShare,
+        SlotNonce
+    );
+}
@@ -0,0 +1,112 @@
+use rand::{CryptoRng, RngCore};
+use secp::{Point, Scalar};
+
+use crate::{
+    error::NoistError,
+    share::{ParticipantIndex, SecretShare},
+    vse::{
+        dleq_prove_with_rng, dleq_verify, vse_decrypt, vse_encrypting_key_from_point, DleqProof,
+        EncryptedShare,
+    },
+    vss::{vss_verify_secret, VssCommitment},
+};
+
+/// Complaint broadcast by a recipient against the dealer of a bad share.
+///
+/// Reveals the ECDH point (Z) for the disputed share's ephemeral key, so everyone can decrypt
+/// that share alone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Complaint {
+    pub accuser: ParticipantIndex,
+    pub accused: ParticipantIndex,
+    pub ecdh_point: Point,
+    pub proof: DleqProof,
+}
+
+/// Outcome of a complaint, naming the participant to disqualify.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    DealerFaulty(ParticipantIndex),
+    AccuserFaulty(ParticipantIndex),
+}
+
+pub fn complaint_create(
+    accuser: ParticipantIndex,
+    accused: ParticipantIndex,
+    self_secret: Scalar,
+    encrypted: &EncryptedShare,
+) -> Result<Complaint, NoistError> {
+    complaint_create_with_rng(
+        &mut rand::thread_rng(),
+        accuser,
+        accused,
+        self_secret,
+        encrypted,
+    )
+}
+
+/// Same as `complaint_create`, drawing the proof nonce from the given RNG.
+pub fn complaint_create_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    accuser: ParticipantIndex,
+    accused: ParticipantIndex,
+    self_secret: Scalar,
+    encrypted: &EncryptedShare,
+) -> Result<Complaint, NoistError> {
+    // ECDH point (Z) is = sk_accuser * R, proven against PK_accuser.
+    let ecdh_point = self_secret * encrypted.ephemeral_key;
+
+    let proof = dleq_prove_with_rng(
+        rng,
+        self_secret,
+        Point::generator(),
+        encrypted.ephemeral_key,
+    )?;
+
+    Ok(Complaint {
+        accuser,
+        accused,
+        ecdh_point,
+        proof,
+    })
+}
+
+/// Settles a complaint against an encrypted share. This is the only point at which a share is
+/// checked to be decryptable by its recipient.
+pub fn complaint_resolve(
+    complaint: &Complaint,
+    accuser_public: Point,
+    encrypted: &EncryptedShare,
+    vss_commitments: &VssCommitment,
+) -> Verdict {
+    // Accuser must reveal the correct ECDH point, otherwise the complaint is false.
+    if !dleq_verify(
+        Point::generator(),
+        accuser_public,
+        encrypted.ephemeral_key,
+        complaint.ecdh_point,
+        &complaint.proof,
+    ) {
+        return Verdict::AccuserFaulty(complaint.accuser);
+    }
+
+    // Pad (k) is = H(Z), and must match the published pad commitment.
+    let pad = vse_encrypting_key_from_point(complaint.ecdh_point);
+
+    if pad.expose().base_point_mul() != encrypted.pad_commitment {
+        return Verdict::DealerFaulty(complaint.accused);
+    }
+
+    // Decrypted share (s_i) is = enc - k.
+    let secret = match vse_decrypt(encrypted.encrypted_share, pad.expose()) {
+        Ok(scalar) => scalar,
+        Err(_) => return Verdict::DealerFaulty(complaint.accused),
+    };
+
+    let secret_share = SecretShare::new(complaint.accuser, secret);
+
+    match vss_verify_secret(&secret_share, vss_commitments) {
+        true => Verdict::AccuserFaulty(complaint.accuser),
+        false => Verdict::DealerFaulty(complaint.accused),
+    }
+}
@@ -0,0 +1,173 @@
+use rand::{CryptoRng, RngCore};
+use secp::{MaybeScalar, Point};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    error::NoistError,
+    secret::{random_scalar_with_rng, secret_share_gen_with_rng},
+    sensitive::SecretScalar,
+    share::{validate_dealer_set, ParticipantIndex, PublicShare, SecretShare},
+    vss::{
+        vss_combine, vss_prove_with_rng, vss_verify_proof, vss_verify_secret, VssCommitment,
+        VssProof,
+    },
+};
+
+/// Public package broadcast by each dealer during key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DKGCommitment {
+    pub index: ParticipantIndex,
+    pub vss_commitments: VssCommitment,
+    pub proof: VssProof,
+}
+
+/// Final key material held by a participant after key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyPackage {
+    pub secret_share: SecretShare,
+    pub public_share: PublicShare,
+    pub group_key: Point,
+    pub vss_commitments: VssCommitment,
+}
+
+impl KeyPackage {
+    /// Key package for a share of the group polynomial. Public share (P_i) is = s_iG and
+    /// the group key (P) is the constant term commitment.
+    pub fn new(secret_share: SecretShare, vss_commitments: VssCommitment) -> KeyPackage {
+        KeyPackage {
+            public_share: secret_share.public_share(),
+            secret_share,
+            group_key: vss_commitments.constant(),
+            vss_commitments,
+        }
+    }
+}
+
+impl Zeroize for KeyPackage {
+    fn zeroize(&mut self) {
+        self.secret_share.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for KeyPackage {}
+
+pub fn dkg_deal(
+    index: ParticipantIndex,
+    num_participants: u32,
+    threshold: u32,
+    context: &[u8],
+) -> Result<(DKGCommitment, Vec<SecretShare>), NoistError> {
+    dkg_deal_with_rng(
+        &mut rand::thread_rng(),
+        index,
+        num_participants,
+        threshold,
+        context,
+    )
+}
+
+/// Same as `dkg_deal`, drawing the secret, coefficients and proof nonce from the given RNG.
+pub fn dkg_deal_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    index: ParticipantIndex,
+    num_participants: u32,
+    threshold: u32,
+    context: &[u8],
+) -> Result<(DKGCommitment, Vec<SecretShare>), NoistError> {
+    // Each dealer picks its own random constant term (a_0).
+    let secret = SecretScalar::new(random_scalar_with_rng(rng)?);
+
+    let (shares, _, vss_commitments) =
+        secret_share_gen_with_rng(rng, secret.expose(), num_participants, threshold)?;
+
+    // Proof of knowledge of a_0, bound to the dealer index and the session context.
+    let proof = vss_prove_with_rng(rng, secret.expose(), index, context)?;
+
+    let commitment = DKGCommitment {
+        index,
+        vss_commitments,
+        proof,
+    };
+
+    Ok((commitment, shares))
+}
+
+/// Checks the dealer's proof of knowledge of a_0 within the session `context`.
+pub fn dkg_verify_commitment(commitment: &DKGCommitment, context: &[u8]) -> bool {
+    vss_verify_proof(
+        &commitment.vss_commitments,
+        commitment.index,
+        context,
+        &commitment.proof,
+    )
+}
+
+pub fn dkg_verify_share(share: &SecretShare, commitment: &DKGCommitment) -> bool {
+    vss_verify_secret(share, &commitment.vss_commitments)
+}
+
+/// Final step, run by each participant over the dealings of the agreed qualified `dealers`.
+///
+/// Every participant must use the same dealer set, otherwise the resulting group keys differ.
+/// Proofs are checked against the session `context` the dealers used in `dkg_deal`.
+pub fn dkg_finalize(
+    index: ParticipantIndex,
+    dealers: &[ParticipantIndex],
+    dealings: &[(DKGCommitment, SecretShare)],
+    threshold: u32,
+    context: &[u8],
+) -> Result<KeyPackage, NoistError> {
+    // Dealings must come from exactly the agreed dealers, each counted only once.
+    let dealer_indexes: Vec<ParticipantIndex> = dealings
+        .iter()
+        .map(|(commitment, _)| commitment.index)
+        .collect();
+
+    validate_dealer_set(dealers, &dealer_indexes, threshold)?;
+
+    let mut secret_share = MaybeScalar::Zero;
+
+    for (commitment, share) in dealings {
+        if commitment.vss_commitments.threshold() != threshold as usize {
+            return Err(NoistError::ThresholdMismatch {
+                expected: threshold as usize,
+                found: commitment.vss_commitments.threshold(),
+            });
+        }
+
+        if !dkg_verify_commitment(commitment, context) {
+            return Err(NoistError::InvalidProof(commitment.index));
+        }
+
+        // The share must be addressed to this participant and match the dealer's commitments.
+        if share.index != index {
+            return Err(NoistError::IndexMismatch {
+                expected: index,
+                found: share.index,
+            });
+        }
+
+        if !dkg_verify_share(share, commitment) {
+            return Err(NoistError::VssMismatch(commitment.index));
+        }
+
+        secret_share += share.secret.expose();
+    }
+
+    let secret_share = match secret_share {
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => scalar,
+    };
+
+    // Group commitments are the sum of every dealer's commitments.
+    let group_vss_commitments = vss_combine(
+        &dealings
+            .iter()
+            .map(|(commitment, _)| &commitment.vss_commitments)
+            .collect::<Vec<_>>(),
+    )?;
+
+    let secret_share = SecretShare::new(index, secret_share);
+
+    Ok(KeyPackage::new(secret_share, group_vss_commitments))
+}
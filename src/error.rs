@@ -0,0 +1,166 @@
+use std::fmt;
+
+use crate::share::ParticipantIndex;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NoistError {
+    InvalidSignature,
+    /// Positions of the invalid signatures in a batch.
+    InvalidSignatures(Vec<usize>),
+    InvalidScalar,
+    InvalidPoint,
+    /// Input bytes have the wrong length.
+    InvalidLength {
+        expected: usize,
+        found: usize,
+    },
+    /// Input bytes could not be parsed at the given byte offset.
+    ParseError {
+        offset: usize,
+    },
+    /// Encoded object has an unknown version byte.
+    UnsupportedVersion(u8),
+    RngFailure,
+    ZeroIndex,
+    IndexOutOfRange {
+        index: u32,
+        num_participants: u32,
+    },
+    /// Group of the given size has no participant index left to issue.
+    IndexExhausted {
+        num_participants: u32,
+    },
+    DuplicateIndex(ParticipantIndex),
+    UnknownIndex(ParticipantIndex),
+    /// Contribution from the given member of the agreed set is missing.
+    MissingIndex(ParticipantIndex),
+    IndexMismatch {
+        expected: ParticipantIndex,
+        found: ParticipantIndex,
+    },
+    ZeroThreshold,
+    /// Threshold is below the minimum the operation supports.
+    ThresholdTooLow {
+        threshold: u32,
+        minimum: u32,
+    },
+    ThresholdExceedsParticipants {
+        threshold: u32,
+        num_participants: u32,
+    },
+    ThresholdMismatch {
+        expected: usize,
+        found: usize,
+    },
+    InsufficientShares {
+        required: usize,
+        provided: usize,
+    },
+    /// Share dealt by the given participant does not match its VSS commitments.
+    VssMismatch(ParticipantIndex),
+    /// Proof of knowledge published by the given participant is invalid.
+    InvalidProof(ParticipantIndex),
+    EncryptionFailure,
+    DecryptionFailure,
+    NonceUnavailable(u64),
+    NonceRangeMismatch {
+        expected: u64,
+        found: u64,
+    },
+}
+
+impl fmt::Display for NoistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoistError::InvalidSignature => write!(f, "invalid signature"),
+            NoistError::InvalidSignatures(positions) => {
+                write!(f, "invalid signatures at positions {:?}", positions)
+            }
+            NoistError::InvalidScalar => write!(f, "invalid scalar"),
+            NoistError::InvalidPoint => write!(f, "invalid point"),
+            NoistError::InvalidLength { expected, found } => {
+                write!(
+                    f,
+                    "invalid length: expected {} bytes, found {}",
+                    expected, found
+                )
+            }
+            NoistError::ParseError { offset } => write!(f, "parse error at byte offset {}", offset),
+            NoistError::UnsupportedVersion(version) => {
+                write!(f, "unsupported encoding version {}", version)
+            }
+            NoistError::RngFailure => write!(f, "random number generator failure"),
+            NoistError::ZeroIndex => write!(f, "participant index must be non-zero"),
+            NoistError::IndexOutOfRange {
+                index,
+                num_participants,
+            } => write!(
+                f,
+                "participant index {} exceeds number of participants {}",
+                index, num_participants
+            ),
+            NoistError::IndexExhausted { num_participants } => write!(
+                f,
+                "no participant index left after {} participants",
+                num_participants
+            ),
+            NoistError::DuplicateIndex(index) => {
+                write!(f, "duplicate participant index {}", index.value())
+            }
+            NoistError::UnknownIndex(index) => {
+                write!(f, "unknown participant index {}", index.value())
+            }
+            NoistError::MissingIndex(index) => {
+                write!(f, "missing participant index {}", index.value())
+            }
+            NoistError::IndexMismatch { expected, found } => write!(
+                f,
+                "participant index mismatch: expected {}, found {}",
+                expected.value(),
+                found.value()
+            ),
+            NoistError::ZeroThreshold => write!(f, "threshold must be non-zero"),
+            NoistError::ThresholdTooLow { threshold, minimum } => write!(
+                f,
+                "threshold {} is below the minimum {}",
+                threshold, minimum
+            ),
+            NoistError::ThresholdExceedsParticipants {
+                threshold,
+                num_participants,
+            } => write!(
+                f,
+                "threshold {} exceeds number of participants {}",
+                threshold, num_participants
+            ),
+            NoistError::ThresholdMismatch { expected, found } => write!(
+                f,
+                "threshold mismatch: expected {}, found {}",
+                expected, found
+            ),
+            NoistError::InsufficientShares { required, provided } => write!(
+                f,
+                "insufficient shares: required {}, provided {}",
+                required, provided
+            ),
+            NoistError::VssMismatch(index) => write!(
+                f,
+                "share from participant {} does not match its VSS commitments",
+                index.value()
+            ),
+            NoistError::InvalidProof(index) => {
+                write!(f, "invalid proof from participant {}", index.value())
+            }
+            NoistError::EncryptionFailure => write!(f, "encryption failure"),
+            NoistError::DecryptionFailure => write!(f, "decryption failure"),
+            NoistError::NonceUnavailable(slot) => write!(f, "nonce slot {} is unavailable", slot),
+            NoistError::NonceRangeMismatch { expected, found } => write!(
+                f,
+                "nonce range mismatch: expected start {}, found {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NoistError {}
@@ -19,26 +19,38 @@ pub enum HashTag {
     BindingValue,
     DeterministicNonce,
     BIP0340Challenge,
+    BIP0340Aux,
+    BIP0340Nonce,
     ProtocolMessageChallenge,
     CustomMessageChallenge,
+    TapTweak,
+    ShareTransportKey,
+    VSEProof,
+    VSSProofOfKnowledge,
     CustomTag(String),
 }
 
 pub fn tagged_hash(data: impl AsRef<[u8]>, tag: HashTag) -> [u8; 32] {
-    let mut preimage = Vec::<u8>::new();
-
     let tag_digest = match tag {
         HashTag::BindingValue => Sha256::digest("Spine/bindingvalue"),
         HashTag::DeterministicNonce => Sha256::digest("Spine/deterministicnonce"),
         HashTag::BIP0340Challenge => Sha256::digest("BIP0340/challenge"),
+        HashTag::BIP0340Aux => Sha256::digest("BIP0340/aux"),
+        HashTag::BIP0340Nonce => Sha256::digest("BIP0340/nonce"),
         HashTag::ProtocolMessageChallenge => Sha256::digest("Spine/protocolmessage/challenge"),
         HashTag::CustomMessageChallenge => Sha256::digest("Spine/custommessage/challenge"),
+        HashTag::TapTweak => Sha256::digest("TapTweak"),
+        HashTag::ShareTransportKey => Sha256::digest("Spine/sharetransportkey"),
+        HashTag::VSEProof => Sha256::digest("Spine/vseproof"),
+        HashTag::VSSProofOfKnowledge => Sha256::digest("Spine/vssproofofknowledge"),
         HashTag::CustomTag(tag) => Sha256::digest(tag),
     };
 
-    preimage.extend(tag_digest);
-    preimage.extend(tag_digest);
-    preimage.extend(data.as_ref());
-
-    sha_256(preimage)
-}
\ No newline at end of file
+    // Data is streamed into the hash rather than copied, as it may be secret.
+    Sha256::new()
+        .chain_update(tag_digest)
+        .chain_update(tag_digest)
+        .chain_update(data.as_ref())
+        .finalize()
+        .into()
+}
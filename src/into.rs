@@ -1,71 +1,73 @@
 use secp::{MaybePoint, MaybeScalar, Point, Scalar};
 
-pub enum ParseError {
-    ParseError32,
-    ParseError33,
-    ParseError64,
-    ParseError65,
-}
-
-#[derive(Debug)]
-pub enum SecpError {
-    InvalidSignature,
-    InvalidScalar,
-    InvalidPoint,
-    SignatureParseError,
-}
+use crate::error::NoistError;
 
+#[allow(clippy::wrong_self_convention)]
 pub trait IntoByteArray {
-    fn into_byte_array_32(&self) -> Result<[u8; 32], ParseError>;
-    fn into_byte_array_33(&self) -> Result<[u8; 33], ParseError>;
-    fn into_byte_array_64(&self) -> Result<[u8; 64], ParseError>;
-    fn into_byte_array_65(&self) -> Result<[u8; 65], ParseError>;
+    fn into_byte_array_32(&self) -> Result<[u8; 32], NoistError>;
+    fn into_byte_array_33(&self) -> Result<[u8; 33], NoistError>;
+    fn into_byte_array_64(&self) -> Result<[u8; 64], NoistError>;
+    fn into_byte_array_65(&self) -> Result<[u8; 65], NoistError>;
 }
 
 impl IntoByteArray for Vec<u8> {
-    fn into_byte_array_32(&self) -> Result<[u8; 32], ParseError> {
+    fn into_byte_array_32(&self) -> Result<[u8; 32], NoistError> {
         let mut vec = Vec::<u8>::with_capacity(32);
         vec.extend(self);
-        let bytes_32: [u8; 32] = vec.try_into().map_err(|_| ParseError::ParseError32)?;
+        let bytes_32: [u8; 32] = vec.try_into().map_err(|_| NoistError::InvalidLength {
+            expected: 32,
+            found: self.len(),
+        })?;
 
         Ok(bytes_32)
     }
 
-    fn into_byte_array_33(&self) -> Result<[u8; 33], ParseError> {
+    fn into_byte_array_33(&self) -> Result<[u8; 33], NoistError> {
         let mut vec = Vec::<u8>::with_capacity(33);
         vec.extend(self);
-        let bytes_33: [u8; 33] = vec.try_into().map_err(|_| ParseError::ParseError33)?;
+        let bytes_33: [u8; 33] = vec.try_into().map_err(|_| NoistError::InvalidLength {
+            expected: 33,
+            found: self.len(),
+        })?;
 
         Ok(bytes_33)
     }
 
-    fn into_byte_array_64(&self) -> Result<[u8; 64], ParseError> {
+    fn into_byte_array_64(&self) -> Result<[u8; 64], NoistError> {
         let mut vec = Vec::<u8>::with_capacity(64);
         vec.extend(self);
-        let bytes_64: [u8; 64] = vec.try_into().map_err(|_| ParseError::ParseError64)?;
+        let bytes_64: [u8; 64] = vec.try_into().map_err(|_| NoistError::InvalidLength {
+            expected: 64,
+            found: self.len(),
+        })?;
 
         Ok(bytes_64)
     }
 
-    fn into_byte_array_65(&self) -> Result<[u8; 65], ParseError> {
+    fn into_byte_array_65(&self) -> Result<[u8; 65], NoistError> {
         let mut vec = Vec::<u8>::with_capacity(65);
         vec.extend(self);
-        let bytes_65: [u8; 65] = vec.try_into().map_err(|_| ParseError::ParseError65)?;
+        let bytes_65: [u8; 65] = vec.try_into().map_err(|_| NoistError::InvalidLength {
+            expected: 65,
+            found: self.len(),
+        })?;
 
         Ok(bytes_65)
     }
 }
 
+#[allow(clippy::wrong_self_convention)]
 pub trait IntoPoint {
-    fn into_point(&self) -> Result<Point, SecpError>;
+    fn into_point(&self) -> Result<Point, NoistError>;
 }
 
+#[allow(clippy::wrong_self_convention)]
 pub trait IntoScalar {
-    fn into_scalar(&self) -> Result<Scalar, SecpError>;
+    fn into_scalar(&self) -> Result<Scalar, NoistError>;
 }
 
 impl IntoPoint for [u8; 32] {
-    fn into_point(&self) -> Result<Point, SecpError> {
+    fn into_point(&self) -> Result<Point, NoistError> {
         let mut point_bytes = Vec::with_capacity(33);
         point_bytes.push(0x02);
         point_bytes.extend(self);
@@ -73,11 +75,11 @@ impl IntoPoint for [u8; 32] {
         let point = match MaybePoint::from_slice(&point_bytes) {
             Ok(maybe_point) => match maybe_point {
                 MaybePoint::Infinity => {
-                    return Err(SecpError::InvalidPoint);
+                    return Err(NoistError::InvalidPoint);
                 }
                 MaybePoint::Valid(point) => point,
             },
-            Err(_) => return Err(SecpError::InvalidPoint),
+            Err(_) => return Err(NoistError::InvalidPoint),
         };
 
         Ok(point)
@@ -85,18 +87,18 @@ impl IntoPoint for [u8; 32] {
 }
 
 impl IntoPoint for [u8; 33] {
-    fn into_point(&self) -> Result<Point, SecpError> {
+    fn into_point(&self) -> Result<Point, NoistError> {
         let mut point_bytes = Vec::with_capacity(33);
         point_bytes.extend(self);
 
         let point = match MaybePoint::from_slice(&point_bytes) {
             Ok(maybe_point) => match maybe_point {
                 MaybePoint::Infinity => {
-                    return Err(SecpError::InvalidPoint);
+                    return Err(NoistError::InvalidPoint);
                 }
                 MaybePoint::Valid(point) => point,
             },
-            Err(_) => return Err(SecpError::InvalidPoint),
+            Err(_) => return Err(NoistError::InvalidPoint),
         };
 
         Ok(point)
@@ -104,44 +106,41 @@ impl IntoPoint for [u8; 33] {
 }
 
 impl IntoPoint for Vec<u8> {
-    fn into_point(&self) -> Result<Point, SecpError> {
+    fn into_point(&self) -> Result<Point, NoistError> {
         match self.len() {
             32 => {
                 let mut bytes = Vec::<u8>::with_capacity(32);
                 bytes.extend(self);
 
-                let ba = bytes
-                    .into_byte_array_32()
-                    .map_err(|_| SecpError::InvalidPoint)?;
-                return ba.into_point();
+                let ba = bytes.into_byte_array_32()?;
+                ba.into_point()
             }
             33 => {
                 let mut bytes = Vec::<u8>::with_capacity(33);
                 bytes.extend(self);
 
-                let ba = bytes
-                    .into_byte_array_33()
-                    .map_err(|_| SecpError::InvalidPoint)?;
-                return ba.into_point();
+                let ba = bytes.into_byte_array_33()?;
+                ba.into_point()
             }
-            _ => return Err(SecpError::InvalidPoint),
+            _ => Err(NoistError::InvalidLength {
+                expected: 33,
+                found: self.len(),
+            }),
         }
     }
 }
 
 impl IntoScalar for [u8; 32] {
-    fn into_scalar(&self) -> Result<Scalar, SecpError> {
-        let mut scalar_bytes = Vec::with_capacity(32);
-        scalar_bytes.extend(self);
-
-        let scalar = match MaybeScalar::from_slice(&scalar_bytes) {
+    fn into_scalar(&self) -> Result<Scalar, NoistError> {
+        // Parsed in place, as the bytes are often a secret key or nonce.
+        let scalar = match MaybeScalar::from_slice(self) {
             Ok(maybe_scalar) => match maybe_scalar {
                 MaybeScalar::Zero => {
-                    return Err(SecpError::InvalidScalar);
+                    return Err(NoistError::InvalidScalar);
                 }
                 MaybeScalar::Valid(point) => point,
             },
-            Err(_) => return Err(SecpError::InvalidScalar),
+            Err(_) => return Err(NoistError::InvalidScalar),
         };
 
         Ok(scalar)
@@ -149,17 +148,11 @@ impl IntoScalar for [u8; 32] {
 }
 
 impl IntoScalar for Vec<u8> {
-    fn into_scalar(&self) -> Result<Scalar, SecpError> {
-        if self.len() != 32 {
-            return Err(SecpError::InvalidPoint);
-        }
-
+    fn into_scalar(&self) -> Result<Scalar, NoistError> {
         let mut bytes = Vec::<u8>::with_capacity(32);
         bytes.extend(self);
 
-        let ba = bytes
-            .into_byte_array_32()
-            .map_err(|_| SecpError::InvalidPoint)?;
+        let ba = bytes.into_byte_array_32()?;
         ba.into_scalar()
     }
 }
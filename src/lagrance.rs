@@ -1,33 +1,94 @@
 use secp::{MaybeScalar, Scalar};
 
-use crate::into::SecpError;
+use crate::{
+    error::NoistError,
+    share::{validate_indexes, ParticipantIndex},
+};
 
-pub fn lagrance_interpolating_value(x_vec: &Vec<Scalar>, x_i: Scalar) -> Result<Scalar, SecpError> {
-    if x_vec.len() == 0 || !x_vec.contains(&x_i) {
-        return Err(SecpError::InvalidScalar);
+pub fn lagrance_interpolating_value(
+    x_vec: &[ParticipantIndex],
+    x_i: ParticipantIndex,
+) -> Result<Scalar, NoistError> {
+    // Duplicate indexes would make the denominator zero.
+    validate_indexes(x_vec, None)?;
+
+    lagrance_interpolating_value_unchecked(x_vec, x_i)
+}
+
+/// Same as `lagrance_interpolating_value`, for callers that checked x_vec for duplicates once
+/// rather than again for every point.
+pub(crate) fn lagrance_interpolating_value_unchecked(
+    x_vec: &[ParticipantIndex],
+    x_i: ParticipantIndex,
+) -> Result<Scalar, NoistError> {
+    if !x_vec.contains(&x_i) {
+        return Err(NoistError::UnknownIndex(x_i));
     }
 
+    let x_i_scalar = x_i.to_scalar();
+
     let mut numerator = Scalar::one();
     let mut denominator = Scalar::one();
 
-    let mut x_i_found = false;
-
     for x_j in x_vec.iter() {
         if x_i == *x_j {
-            x_i_found = true;
             continue;
         }
 
-        numerator = numerator * x_j.to_owned();
+        let x_j_scalar = x_j.to_scalar();
+
+        numerator *= x_j_scalar;
+
+        denominator *= match x_j_scalar - x_i_scalar {
+            MaybeScalar::Valid(scalar) => scalar,
+            MaybeScalar::Zero => return Err(NoistError::DuplicateIndex(*x_j)),
+        };
+    }
+
+    let result = numerator * denominator.invert();
+
+    Ok(result)
+}
+
+/// Lagrange basis polynomial for x_i over x_vec, evaluated at a point x outside of x_vec.
+pub fn lagrance_interpolating_value_at(
+    x_vec: &[ParticipantIndex],
+    x_i: ParticipantIndex,
+    x: ParticipantIndex,
+) -> Result<Scalar, NoistError> {
+    if !x_vec.contains(&x_i) {
+        return Err(NoistError::UnknownIndex(x_i));
+    }
 
-        denominator = denominator
-            * match x_j.to_owned() - x_i.to_owned() {
-                MaybeScalar::Valid(scalar) => scalar,
-                MaybeScalar::Zero => return Err(SecpError::InvalidScalar),
-            };
+    // Evaluating at one of x_vec would make the numerator zero.
+    if x_vec.contains(&x) {
+        return Err(NoistError::DuplicateIndex(x));
     }
-    if !x_i_found {
-        return Err(SecpError::InvalidScalar);
+
+    validate_indexes(x_vec, None)?;
+
+    let x_scalar = x.to_scalar();
+    let x_i_scalar = x_i.to_scalar();
+
+    let mut numerator = Scalar::one();
+    let mut denominator = Scalar::one();
+
+    for x_j in x_vec.iter() {
+        if x_i == *x_j {
+            continue;
+        }
+
+        let x_j_scalar = x_j.to_scalar();
+
+        numerator *= match x_scalar - x_j_scalar {
+            MaybeScalar::Valid(scalar) => scalar,
+            MaybeScalar::Zero => return Err(NoistError::DuplicateIndex(*x_j)),
+        };
+
+        denominator *= match x_i_scalar - x_j_scalar {
+            MaybeScalar::Valid(scalar) => scalar,
+            MaybeScalar::Zero => return Err(NoistError::DuplicateIndex(*x_j)),
+        };
     }
 
     let result = numerator * denominator.invert();
@@ -1,7 +1,21 @@
+pub mod codec;
+pub mod complaint;
+pub mod dkg;
+pub mod error;
 pub mod hash;
 pub mod into;
 pub mod lagrance;
+pub mod nonce;
+pub mod pedersen;
+pub mod refresh;
+pub mod repair;
+pub mod reshare;
 pub mod schnorr;
 pub mod secret;
+pub mod sensitive;
+pub mod session;
+pub mod share;
+pub mod transport;
+pub mod tweak;
+pub mod vse;
 pub mod vss;
-pub mod vse;
\ No newline at end of file
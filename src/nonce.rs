@@ -0,0 +1,394 @@
+use std::collections::BTreeMap;
+
+use rand::{CryptoRng, RngCore};
+use secp::{MaybePoint, MaybeScalar, Point, Scalar};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    error::NoistError,
+    hash::{tagged_hash, HashTag},
+    secret::{random_scalar_with_rng, secret_share_shard_polynomial},
+    sensitive::SecretPolynomial,
+    share::{validate_dealer_set, ParticipantIndex, PublicShare, SecretShare},
+    vss::{vss_combine, vss_commit, vss_public_share, vss_verify_secret, VssCommitment},
+};
+
+/// Commitments to the hiding (d) and binding (e) nonce polynomials of a single slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonceCommitment {
+    pub hiding: VssCommitment,
+    pub binding: VssCommitment,
+}
+
+/// Shares (d_i, e_i) of the hiding and binding nonces of a single slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonceShare {
+    pub hiding: SecretShare,
+    pub binding: SecretShare,
+}
+
+/// Public part of a batch of nonces dealt by a single signer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonceDealing {
+    pub dealer: ParticipantIndex,
+    pub start: u64,
+    pub vss_commitments: Vec<NonceCommitment>,
+}
+
+/// Nonce shares dealt to each participant, one share pair per nonce slot.
+pub type NonceShares = Vec<Vec<NonceShare>>;
+
+/// Group commitments of a single pool slot, known to everyone before the message is chosen.
+///
+/// The signing nonce (R) is only fixed once bound to a message, so that pre-generated slots
+/// cannot be combined across messages (ROS attack).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlotNonce {
+    pub slot: u64,
+    pub vss_commitments: NonceCommitment,
+}
+
+/// A single pre-generated nonce as held by a participant.
+#[derive(Clone, Debug)]
+pub struct NonceEntry {
+    pub nonce_share: NonceShare,
+    pub slot_nonce: SlotNonce,
+}
+
+/// Indexed pool of pre-generated nonces held by a participant.
+#[derive(Clone, Debug)]
+pub struct NoncePool {
+    index: ParticipantIndex,
+    threshold: u32,
+    end: u64,
+    entries: BTreeMap<u64, NonceEntry>,
+}
+
+impl NonceShare {
+    pub fn index(&self) -> ParticipantIndex {
+        self.hiding.index
+    }
+}
+
+impl Zeroize for NonceShare {
+    fn zeroize(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for NonceShare {}
+
+impl SlotNonce {
+    /// Hiding nonce (D) is the constant term of the hiding commitments.
+    pub fn hiding_nonce(&self) -> Point {
+        self.vss_commitments.hiding.constant()
+    }
+
+    /// Binding nonce (E) is the constant term of the binding commitments.
+    pub fn binding_nonce(&self) -> Point {
+        self.vss_commitments.binding.constant()
+    }
+
+    /// Binding value (ρ) is = int(H(slot||D||E||m)) mod n.
+    pub fn binding_value(&self, message_bytes: &[u8]) -> Result<Scalar, NoistError> {
+        let mut binding_preimage = Vec::<u8>::with_capacity(74 + message_bytes.len());
+        binding_preimage.extend(self.slot.to_be_bytes());
+        binding_preimage.extend(self.hiding_nonce().serialize());
+        binding_preimage.extend(self.binding_nonce().serialize());
+        binding_preimage.extend(message_bytes);
+
+        match MaybeScalar::reduce_from(&tagged_hash(binding_preimage, HashTag::BindingValue)) {
+            MaybeScalar::Zero => Err(NoistError::InvalidScalar),
+            MaybeScalar::Valid(scalar) => Ok(scalar),
+        }
+    }
+
+    /// Group nonce (R) is = D + ρE.
+    pub fn group_nonce(&self, message_bytes: &[u8]) -> Result<Point, NoistError> {
+        let binding_value = self.binding_value(message_bytes)?;
+
+        match self.hiding_nonce() + binding_value * self.binding_nonce() {
+            MaybePoint::Infinity => Err(NoistError::InvalidPoint),
+            MaybePoint::Valid(point) => Ok(point),
+        }
+    }
+
+    /// Public nonce share (R_i) is = D_i + ρE_i, evaluated from the commitments.
+    pub fn public_nonce_share(
+        &self,
+        index: ParticipantIndex,
+        message_bytes: &[u8],
+    ) -> Result<PublicShare, NoistError> {
+        let binding_value = self.binding_value(message_bytes)?;
+
+        let hiding_share = vss_public_share(index, &self.vss_commitments.hiding)?;
+        let binding_share = vss_public_share(index, &self.vss_commitments.binding)?;
+
+        match hiding_share.point + binding_value * binding_share.point {
+            MaybePoint::Infinity => Err(NoistError::InvalidPoint),
+            MaybePoint::Valid(point) => Ok(PublicShare::new(index, point)),
+        }
+    }
+}
+
+impl NonceEntry {
+    /// Nonce share (k_i) bound to the message is = d_i + ρe_i.
+    pub(crate) fn signing_nonce_share(
+        &self,
+        message_bytes: &[u8],
+    ) -> Result<SecretShare, NoistError> {
+        let binding_value = self.slot_nonce.binding_value(message_bytes)?;
+
+        let hiding = self.nonce_share.hiding.secret.expose();
+        let binding = self.nonce_share.binding.secret.expose();
+
+        match hiding + binding_value * binding {
+            MaybeScalar::Zero => Err(NoistError::InvalidScalar),
+            MaybeScalar::Valid(scalar) => Ok(SecretShare::new(self.nonce_share.index(), scalar)),
+        }
+    }
+}
+
+impl Zeroize for NonceEntry {
+    fn zeroize(&mut self) {
+        self.nonce_share.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for NonceEntry {}
+
+pub fn nonce_deal(
+    dealer: ParticipantIndex,
+    start: u64,
+    count: usize,
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(NonceDealing, NonceShares), NoistError> {
+    nonce_deal_with_rng(
+        &mut rand::thread_rng(),
+        dealer,
+        start,
+        count,
+        num_participants,
+        threshold,
+    )
+}
+
+/// Same as `nonce_deal`, drawing the nonces and coefficients from the given RNG.
+pub fn nonce_deal_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    dealer: ParticipantIndex,
+    start: u64,
+    count: usize,
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(NonceDealing, NonceShares), NoistError> {
+    if threshold == 0 {
+        return Err(NoistError::ZeroThreshold);
+    }
+
+    if threshold > num_participants {
+        return Err(NoistError::ThresholdExceedsParticipants {
+            threshold,
+            num_participants,
+        });
+    }
+
+    let mut vss_commitments = Vec::<NonceCommitment>::with_capacity(count);
+
+    // Shares are grouped per participant, each holding one share pair per nonce.
+    let mut participant_shares =
+        vec![Vec::<NonceShare>::with_capacity(count); num_participants as usize];
+
+    for _ in 0..count {
+        // Hiding (d) and binding (e) nonces are shared with independent polynomials.
+        let hiding_constant = random_scalar_with_rng(rng)?;
+        let hiding_polynomial =
+            SecretPolynomial::random_with_rng(rng, hiding_constant, threshold as usize - 1)?;
+
+        let binding_constant = random_scalar_with_rng(rng)?;
+        let binding_polynomial =
+            SecretPolynomial::random_with_rng(rng, binding_constant, threshold as usize - 1)?;
+
+        let hiding_shares = secret_share_shard_polynomial(&hiding_polynomial, num_participants)?;
+        let binding_shares = secret_share_shard_polynomial(&binding_polynomial, num_participants)?;
+
+        vss_commitments.push(NonceCommitment {
+            hiding: vss_commit(&hiding_polynomial)?,
+            binding: vss_commit(&binding_polynomial)?,
+        });
+
+        for (participant, (hiding, binding)) in
+            hiding_shares.into_iter().zip(binding_shares).enumerate()
+        {
+            participant_shares[participant].push(NonceShare { hiding, binding });
+        }
+    }
+
+    let dealing = NonceDealing {
+        dealer,
+        start,
+        vss_commitments,
+    };
+
+    Ok((dealing, participant_shares))
+}
+
+impl NoncePool {
+    pub fn new(index: ParticipantIndex, threshold: u32) -> NoncePool {
+        NoncePool {
+            index,
+            threshold,
+            end: 0,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn index(&self) -> ParticipantIndex {
+        self.index
+    }
+
+    /// Next nonce slot that has not been dealt yet.
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Number of nonces left to consume.
+    pub fn remaining(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, slot: u64) -> Option<&NonceEntry> {
+        self.entries.get(&slot)
+    }
+
+    /// Removes the nonce at the given slot so that it can never be used twice.
+    pub fn consume(&mut self, slot: u64) -> Result<NonceEntry, NoistError> {
+        self.entries
+            .remove(&slot)
+            .ok_or(NoistError::NonceUnavailable(slot))
+    }
+
+    /// Combines a batch of dealings, one from each of the agreed `dealers`, into new pool entries.
+    ///
+    /// Every signer must use the same dealer set, otherwise their group nonces differ.
+    pub fn replenish(
+        &mut self,
+        dealers: &[ParticipantIndex],
+        dealings: &[(NonceDealing, Vec<NonceShare>)],
+    ) -> Result<(), NoistError> {
+        let dealer_indexes: Vec<ParticipantIndex> =
+            dealings.iter().map(|(dealing, _)| dealing.dealer).collect();
+
+        validate_dealer_set(dealers, &dealer_indexes, self.threshold)?;
+
+        // Every dealer must cover the same range, starting right after the current pool.
+        let count = match dealings.first() {
+            Some((dealing, _)) => dealing.vss_commitments.len(),
+            None => {
+                return Err(NoistError::InsufficientShares {
+                    required: 1,
+                    provided: 0,
+                })
+            }
+        };
+
+        for (dealing, shares) in dealings {
+            if dealing.start != self.end {
+                return Err(NoistError::NonceRangeMismatch {
+                    expected: self.end,
+                    found: dealing.start,
+                });
+            }
+
+            if dealing.vss_commitments.len() != count || shares.len() != count {
+                return Err(NoistError::InvalidLength {
+                    expected: count,
+                    found: shares.len().min(dealing.vss_commitments.len()),
+                });
+            }
+
+            for (vss_commitments, share) in dealing.vss_commitments.iter().zip(shares) {
+                for (vss_commitments, share) in [
+                    (&vss_commitments.hiding, &share.hiding),
+                    (&vss_commitments.binding, &share.binding),
+                ] {
+                    if vss_commitments.threshold() != self.threshold as usize {
+                        return Err(NoistError::ThresholdMismatch {
+                            expected: self.threshold as usize,
+                            found: vss_commitments.threshold(),
+                        });
+                    }
+
+                    if share.index != self.index {
+                        return Err(NoistError::IndexMismatch {
+                            expected: self.index,
+                            found: share.index,
+                        });
+                    }
+
+                    if !vss_verify_secret(share, vss_commitments) {
+                        return Err(NoistError::VssMismatch(dealing.dealer));
+                    }
+                }
+            }
+        }
+
+        let mut entries = Vec::<NonceEntry>::with_capacity(count);
+
+        for slot in 0..count {
+            let mut hiding_share = MaybeScalar::Zero;
+            let mut binding_share = MaybeScalar::Zero;
+
+            for (_, shares) in dealings {
+                hiding_share += shares[slot].hiding.secret.expose();
+                binding_share += shares[slot].binding.secret.expose();
+            }
+
+            let (hiding_share, binding_share) = match (hiding_share, binding_share) {
+                (MaybeScalar::Valid(hiding), MaybeScalar::Valid(binding)) => (hiding, binding),
+                _ => return Err(NoistError::InvalidScalar),
+            };
+
+            let hiding_vss_commitments = vss_combine(
+                &dealings
+                    .iter()
+                    .map(|(dealing, _)| &dealing.vss_commitments[slot].hiding)
+                    .collect::<Vec<_>>(),
+            )?;
+
+            let binding_vss_commitments = vss_combine(
+                &dealings
+                    .iter()
+                    .map(|(dealing, _)| &dealing.vss_commitments[slot].binding)
+                    .collect::<Vec<_>>(),
+            )?;
+
+            entries.push(NonceEntry {
+                nonce_share: NonceShare {
+                    hiding: SecretShare::new(self.index, hiding_share),
+                    binding: SecretShare::new(self.index, binding_share),
+                },
+                slot_nonce: SlotNonce {
+                    slot: self.end + slot as u64,
+                    vss_commitments: NonceCommitment {
+                        hiding: hiding_vss_commitments,
+                        binding: binding_vss_commitments,
+                    },
+                },
+            });
+        }
+
+        for entry in entries {
+            self.entries.insert(self.end, entry);
+            self.end += 1;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use std::{fmt, sync::OnceLock};
+
+use k256::{
+    elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest},
+    Secp256k1,
+};
+use rand::{CryptoRng, RngCore};
+use secp::{MaybePoint, MaybeScalar, Point, Scalar};
+use sha2::Sha256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    error::NoistError,
+    secret::{random_scalar_with_rng, secret_share_shard_polynomial},
+    sensitive::{SecretPolynomial, SecretScalar},
+    share::{ParticipantIndex, SecretShare},
+    vss::{multi_scalar_mul, point_from_projective, scalar_powers},
+};
+
+/// Domain separation tag for deriving the second generator (H).
+pub const PEDERSEN_GENERATOR_DST: &[u8] = b"Spine/pedersengenerator/secp256k1_XMD:SHA-256_SSWU_RO_";
+
+/// Pedersen commitments (C_0, ..., C_t-1) to two polynomials, C_j = a_j*G + b_j*H.
+///
+/// Unlike `VssCommitment`, these reveal nothing about the secret, not even sG.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PedersenCommitment(Vec<Point>);
+
+/// Pedersen share (x, f(x), g(x)), where g is the blinding polynomial. `Debug` redacts both.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PedersenShare {
+    pub index: ParticipantIndex,
+    pub secret: SecretScalar,
+    pub blinding: SecretScalar,
+}
+
+impl PedersenCommitment {
+    pub fn new(points: Vec<Point>) -> Result<PedersenCommitment, NoistError> {
+        match points.is_empty() {
+            true => Err(NoistError::InvalidPoint),
+            false => Ok(PedersenCommitment(points)),
+        }
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.0
+    }
+
+    /// Number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl PedersenShare {
+    pub fn new(index: ParticipantIndex, secret: Scalar, blinding: Scalar) -> PedersenShare {
+        PedersenShare {
+            index,
+            secret: SecretScalar::new(secret),
+            blinding: SecretScalar::new(blinding),
+        }
+    }
+
+    /// Plain share (x, f(x)), usable with `secret_share_combine` once verified.
+    pub fn secret_share(&self) -> SecretShare {
+        SecretShare::new(self.index, self.secret.expose())
+    }
+}
+
+impl Zeroize for PedersenShare {
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+        self.blinding.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for PedersenShare {}
+
+impl fmt::Debug for PedersenShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PedersenShare")
+            .field("index", &self.index)
+            .field("secret", &"<redacted>")
+            .field("blinding", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Second generator (H) with no known discrete log relative to G, derived via RFC 9380
+/// hash-to-curve (secp256k1_XMD:SHA-256_SSWU_RO_).
+pub fn pedersen_generator() -> Point {
+    static GENERATOR: OnceLock<Point> = OnceLock::new();
+
+    *GENERATOR.get_or_init(|| {
+        let point =
+            Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"H"], &[PEDERSEN_GENERATOR_DST])
+                .expect("tag is a valid hash-to-curve domain separator");
+
+        match point_from_projective(point) {
+            MaybePoint::Valid(point) => point,
+            MaybePoint::Infinity => unreachable!("hash-to-curve never yields the identity"),
+        }
+    })
+}
+
+pub fn pedersen_commit(
+    coeffs: &[Scalar],
+    blinding_coeffs: &[Scalar],
+) -> Result<PedersenCommitment, NoistError> {
+    if coeffs.len() != blinding_coeffs.len() {
+        return Err(NoistError::ThresholdMismatch {
+            expected: coeffs.len(),
+            found: blinding_coeffs.len(),
+        });
+    }
+
+    let generator = pedersen_generator();
+
+    let mut pedersen_commitments = Vec::<Point>::with_capacity(coeffs.len());
+
+    for (coeff, blinding_coeff) in coeffs.iter().zip(blinding_coeffs.iter()) {
+        // Commitment (C_j) is = a_j*G + b_j*H.
+        match coeff.base_point_mul() + *blinding_coeff * generator {
+            MaybePoint::Valid(point) => pedersen_commitments.push(point),
+            MaybePoint::Infinity => return Err(NoistError::InvalidPoint),
+        }
+    }
+
+    PedersenCommitment::new(pedersen_commitments)
+}
+
+pub fn pedersen_share_gen(
+    secret_key: Scalar,
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(Vec<PedersenShare>, PedersenCommitment), NoistError> {
+    pedersen_share_gen_with_rng(
+        &mut rand::thread_rng(),
+        secret_key,
+        num_participants,
+        threshold,
+    )
+}
+
+/// Same as `pedersen_share_gen`, drawing both polynomials from the given RNG.
+pub fn pedersen_share_gen_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret_key: Scalar,
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(Vec<PedersenShare>, PedersenCommitment), NoistError> {
+    if threshold == 0 {
+        return Err(NoistError::ZeroThreshold);
+    }
+
+    if threshold > num_participants {
+        return Err(NoistError::ThresholdExceedsParticipants {
+            threshold,
+            num_participants,
+        });
+    }
+
+    // Secret polynomial (f) has the secret as a_0, blinding polynomial (g) is fully random.
+    let polynomial = SecretPolynomial::random_with_rng(rng, secret_key, threshold as usize - 1)?;
+
+    let blinding_constant = random_scalar_with_rng(rng)?;
+    let blinding_polynomial =
+        SecretPolynomial::random_with_rng(rng, blinding_constant, threshold as usize - 1)?;
+
+    let secret_shares = secret_share_shard_polynomial(&polynomial, num_participants)?;
+    let blinding_shares = secret_share_shard_polynomial(&blinding_polynomial, num_participants)?;
+
+    let pedersen_commitments = pedersen_commit(&polynomial, &blinding_polynomial)?;
+
+    let shares = secret_shares
+        .iter()
+        .zip(blinding_shares.iter())
+        .map(|(share, blinding)| {
+            PedersenShare::new(share.index, share.secret.expose(), blinding.secret.expose())
+        })
+        .collect();
+
+    Ok((shares, pedersen_commitments))
+}
+
+/// Same as `vss_verify_secret`, for Pedersen shares and commitments.
+#[allow(non_snake_case)]
+pub fn pedersen_verify_secret(
+    share_i: &PedersenShare,
+    pedersen_commitments: &PedersenCommitment,
+) -> bool {
+    // Committed share (S_i) is = s_i*G + t_i*H.
+    let S_i = multi_scalar_mul(&[
+        (
+            Point::generator(),
+            MaybeScalar::Valid(share_i.secret.expose()),
+        ),
+        (
+            pedersen_generator(),
+            MaybeScalar::Valid(share_i.blinding.expose()),
+        ),
+    ]);
+
+    // Expected value is = sum(C_j * i^j).
+    let powers = scalar_powers(share_i.index.to_scalar(), pedersen_commitments.threshold());
+
+    let terms: Vec<(Point, MaybeScalar)> = pedersen_commitments
+        .points()
+        .iter()
+        .copied()
+        .zip(powers.into_iter().map(MaybeScalar::Valid))
+        .collect();
+
+    match multi_scalar_mul(&terms) {
+        MaybePoint::Infinity => false,
+        S_i_computed => S_i == S_i_computed,
+    }
+}
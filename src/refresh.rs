@@ -0,0 +1,159 @@
+use rand::{CryptoRng, RngCore};
+use secp::{MaybePoint, MaybeScalar};
+
+use crate::{
+    dkg::KeyPackage,
+    error::NoistError,
+    secret::{random_scalar_with_rng, secret_share_shard_polynomial},
+    sensitive::SecretPolynomial,
+    share::{validate_dealer_set, ParticipantIndex, SecretShare},
+    vss::{vss_combine, vss_commit, vss_public_share, vss_verify_secret, VssCommitment},
+};
+
+/// Public package broadcast by each participant during a share refresh.
+///
+/// Commitments are to the coefficients (a_1, ..., a_t-1) of a polynomial with zero constant term.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshDealing {
+    pub dealer: ParticipantIndex,
+    pub vss_commitments: VssCommitment,
+}
+
+pub fn refresh_deal(
+    dealer: ParticipantIndex,
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(RefreshDealing, Vec<SecretShare>), NoistError> {
+    refresh_deal_with_rng(&mut rand::thread_rng(), dealer, num_participants, threshold)
+}
+
+/// Same as `refresh_deal`, drawing the coefficients from the given RNG.
+pub fn refresh_deal_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    dealer: ParticipantIndex,
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(RefreshDealing, Vec<SecretShare>), NoistError> {
+    // Refresh polynomial (f) is = x * g(x), so that f(0) is zero and g has t-1 coefficients.
+    if threshold == 0 {
+        return Err(NoistError::ZeroThreshold);
+    }
+
+    // Threshold 1 has no polynomial terms beyond the constant to rerandomize.
+    if threshold < 2 {
+        return Err(NoistError::ThresholdTooLow {
+            threshold,
+            minimum: 2,
+        });
+    }
+
+    let constant = random_scalar_with_rng(rng)?;
+    let polynomial = SecretPolynomial::random_with_rng(rng, constant, threshold as usize - 2)?;
+
+    let g_shares = secret_share_shard_polynomial(&polynomial, num_participants)?;
+
+    let vss_commitments = vss_commit(&polynomial)?;
+
+    // Refresh share (δ_i) is = i * g(i).
+    let shares = g_shares
+        .into_iter()
+        .map(|share| SecretShare::new(share.index, share.index.to_scalar() * share.secret.expose()))
+        .collect();
+
+    Ok((
+        RefreshDealing {
+            dealer,
+            vss_commitments,
+        },
+        shares,
+    ))
+}
+
+pub fn refresh_verify_share(share: &SecretShare, dealing: &RefreshDealing) -> bool {
+    // Check if δ_iG equals to i * sum(R_j * i^(j-1)).
+    match vss_public_share(share.index, &dealing.vss_commitments) {
+        Ok(public_share) => {
+            share.secret.expose().base_point_mul() == share.index.to_scalar() * public_share.point
+        }
+        Err(_) => false,
+    }
+}
+
+/// Final step, run by each participant over the dealings of the agreed `dealers`.
+///
+/// Every participant must use the same dealer set of at least t dealers, otherwise the updated
+/// commitments differ and the refreshed shares no longer interpolate to the group key.
+pub fn refresh_finalize(
+    key_package: &KeyPackage,
+    dealers: &[ParticipantIndex],
+    dealings: &[(RefreshDealing, SecretShare)],
+) -> Result<KeyPackage, NoistError> {
+    let index = key_package.secret_share.index;
+    let threshold = key_package.vss_commitments.threshold();
+
+    // Dealings must come from exactly the agreed dealers, each counted only once.
+    let dealer_indexes: Vec<ParticipantIndex> =
+        dealings.iter().map(|(dealing, _)| dealing.dealer).collect();
+
+    validate_dealer_set(dealers, &dealer_indexes, threshold as u32)?;
+
+    let mut secret_share = MaybeScalar::Valid(key_package.secret_share.secret.expose());
+
+    for (dealing, share) in dealings {
+        if dealing.vss_commitments.threshold() + 1 != threshold {
+            return Err(NoistError::ThresholdMismatch {
+                expected: threshold,
+                found: dealing.vss_commitments.threshold() + 1,
+            });
+        }
+
+        if share.index != index {
+            return Err(NoistError::IndexMismatch {
+                expected: index,
+                found: share.index,
+            });
+        }
+
+        if !refresh_verify_share(share, dealing) {
+            return Err(NoistError::VssMismatch(dealing.dealer));
+        }
+
+        secret_share += share.secret.expose();
+    }
+
+    let secret_share = match secret_share {
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => SecretShare::new(index, scalar),
+    };
+
+    let refresh_commitments = vss_combine(
+        &dealings
+            .iter()
+            .map(|(dealing, _)| &dealing.vss_commitments)
+            .collect::<Vec<_>>(),
+    )?;
+
+    // Updated commitments keep A_0 and add the refresh commitments to A_1, ..., A_t-1.
+    let mut points = vec![key_package.vss_commitments.constant()];
+
+    for (old, refresh) in key_package.vss_commitments.points()[1..]
+        .iter()
+        .zip(refresh_commitments.points())
+    {
+        points.push(match *old + *refresh {
+            MaybePoint::Infinity => return Err(NoistError::InvalidPoint),
+            MaybePoint::Valid(point) => point,
+        });
+    }
+
+    let vss_commitments = VssCommitment::new(points)?;
+
+    // Updated commitments must still evaluate to the same group key and the updated share.
+    if vss_commitments.constant() != key_package.group_key
+        || !vss_verify_secret(&secret_share, &vss_commitments)
+    {
+        return Err(NoistError::VssMismatch(index));
+    }
+
+    Ok(KeyPackage::new(secret_share, vss_commitments))
+}
@@ -0,0 +1,193 @@
+use rand::{CryptoRng, RngCore};
+use secp::{MaybeScalar, Scalar};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    dkg::KeyPackage,
+    error::NoistError,
+    lagrance::lagrance_interpolating_value_at,
+    secret::random_scalar_with_rng,
+    sensitive::SecretScalar,
+    share::{validate_indexes, ParticipantIndex, SecretShare},
+    vss::{vss_verify_point, vss_verify_secret, VssCommitment},
+};
+
+/// Masked value sent between participants while repairing a lost share.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RepairContribution {
+    pub from: ParticipantIndex,
+    pub to: ParticipantIndex,
+    pub value: SecretScalar,
+}
+
+impl Zeroize for RepairContribution {
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for RepairContribution {}
+
+/// First step, run by each helper. Splits λ_i(r) * s_i into one random summand per helper.
+pub fn repair_deal(
+    secret_share: &SecretShare,
+    helpers: &[ParticipantIndex],
+    lost_index: ParticipantIndex,
+) -> Result<Vec<RepairContribution>, NoistError> {
+    repair_deal_with_rng(&mut rand::thread_rng(), secret_share, helpers, lost_index)
+}
+
+/// Same as `repair_deal`, drawing the random summands from the given RNG.
+pub fn repair_deal_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret_share: &SecretShare,
+    helpers: &[ParticipantIndex],
+    lost_index: ParticipantIndex,
+) -> Result<Vec<RepairContribution>, NoistError> {
+    // Delta (δ_i) is = λ_i(r) * s_i, so that the helpers' deltas sum to s_r.
+    let lambda = lagrance_interpolating_value_at(helpers, secret_share.index, lost_index)?;
+    let delta = lambda * secret_share.secret.expose();
+
+    // Every summand but the last is random, which masks δ_i from the other helpers.
+    let mut remainder = MaybeScalar::Valid(delta);
+    let mut contributions = Vec::<RepairContribution>::with_capacity(helpers.len());
+
+    for (position, helper) in helpers.iter().enumerate() {
+        let value = match position + 1 == helpers.len() {
+            true => match remainder {
+                MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+                MaybeScalar::Valid(scalar) => scalar,
+            },
+            false => random_scalar_with_rng(rng)?,
+        };
+
+        remainder -= value;
+
+        contributions.push(RepairContribution {
+            from: secret_share.index,
+            to: *helper,
+            value: SecretScalar::new(value),
+        });
+    }
+
+    Ok(contributions)
+}
+
+fn repair_sum(
+    index: ParticipantIndex,
+    helpers: &[ParticipantIndex],
+    contributions: &[RepairContribution],
+) -> Result<Scalar, NoistError> {
+    if contributions.len() != helpers.len() {
+        return Err(NoistError::InsufficientShares {
+            required: helpers.len(),
+            provided: contributions.len(),
+        });
+    }
+
+    let senders: Vec<ParticipantIndex> = contributions
+        .iter()
+        .map(|contribution| contribution.from)
+        .collect();
+
+    validate_indexes(&senders, None)?;
+
+    let mut sum = MaybeScalar::Zero;
+
+    for contribution in contributions {
+        if !helpers.contains(&contribution.from) {
+            return Err(NoistError::UnknownIndex(contribution.from));
+        }
+
+        if contribution.to != index {
+            return Err(NoistError::IndexMismatch {
+                expected: index,
+                found: contribution.to,
+            });
+        }
+
+        sum += contribution.value.expose();
+    }
+
+    match sum {
+        MaybeScalar::Zero => Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => Ok(scalar),
+    }
+}
+
+/// Second step, run by each helper. Sums the summands received from every helper.
+pub fn repair_combine(
+    index: ParticipantIndex,
+    helpers: &[ParticipantIndex],
+    lost_index: ParticipantIndex,
+    contributions: &[RepairContribution],
+) -> Result<RepairContribution, NoistError> {
+    // Sigma (σ_j) is = sum(δ_i,j).
+    let value = repair_sum(index, helpers, contributions)?;
+
+    Ok(RepairContribution {
+        from: index,
+        to: lost_index,
+        value: SecretScalar::new(value),
+    })
+}
+
+/// Final step, run by the participant who lost their share.
+pub fn repair_finalize(
+    lost_index: ParticipantIndex,
+    helpers: &[ParticipantIndex],
+    contributions: &[RepairContribution],
+    vss_commitments: &VssCommitment,
+) -> Result<SecretShare, NoistError> {
+    if helpers.len() < vss_commitments.threshold() {
+        return Err(NoistError::InsufficientShares {
+            required: vss_commitments.threshold(),
+            provided: helpers.len(),
+        });
+    }
+
+    // Repaired share (s_r) is = sum(σ_j).
+    let secret_share =
+        SecretShare::new(lost_index, repair_sum(lost_index, helpers, contributions)?);
+
+    match vss_verify_secret(&secret_share, vss_commitments) {
+        true => Ok(secret_share),
+        false => Err(NoistError::VssMismatch(lost_index)),
+    }
+}
+
+/// Index (n+1) issued to a new member joining a group of n participants.
+pub fn enroll_index(num_participants: u32) -> Result<ParticipantIndex, NoistError> {
+    match num_participants.checked_add(1) {
+        Some(index) => ParticipantIndex::new(index),
+        None => Err(NoistError::IndexExhausted { num_participants }),
+    }
+}
+
+/// Final step of enrollment, run by the new member. Helpers run `repair_deal` and
+/// `repair_combine` for the index given by `enroll_index`.
+pub fn enroll_finalize(
+    num_participants: u32,
+    helpers: &[ParticipantIndex],
+    contributions: &[RepairContribution],
+    vss_commitments: &VssCommitment,
+) -> Result<KeyPackage, NoistError> {
+    let index = enroll_index(num_participants)?;
+
+    if helpers.len() < vss_commitments.threshold() {
+        return Err(NoistError::InsufficientShares {
+            required: vss_commitments.threshold(),
+            provided: helpers.len(),
+        });
+    }
+
+    // Enrolled share (s_n+1) is = sum(σ_j), which is f(n+1) of the group polynomial.
+    let secret_share = SecretShare::new(index, repair_sum(index, helpers, contributions)?);
+
+    // Public share (P_n+1) must match the existing commitments evaluated at n+1.
+    if !vss_verify_point(&secret_share.public_share(), vss_commitments) {
+        return Err(NoistError::VssMismatch(index));
+    }
+
+    Ok(KeyPackage::new(secret_share, vss_commitments.clone()))
+}
@@ -0,0 +1,147 @@
+use rand::{CryptoRng, RngCore};
+use secp::MaybeScalar;
+
+use crate::{
+    dkg::KeyPackage,
+    error::NoistError,
+    lagrance::lagrance_interpolating_value,
+    secret::secret_share_gen_with_rng,
+    share::{validate_dealer_set, ParticipantIndex, SecretShare},
+    vss::{vss_combine, vss_public_share, vss_verify_secret, VssCommitment},
+};
+
+/// Public package broadcast by an old share holder when resharing to a new participant set.
+///
+/// Commitments are to a polynomial of the new threshold whose constant term is λ_i * s_i.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReshareDealing {
+    pub dealer: ParticipantIndex,
+    pub vss_commitments: VssCommitment,
+}
+
+pub fn reshare_deal(
+    secret_share: &SecretShare,
+    dealers: &[ParticipantIndex],
+    new_num_participants: u32,
+    new_threshold: u32,
+) -> Result<(ReshareDealing, Vec<SecretShare>), NoistError> {
+    reshare_deal_with_rng(
+        &mut rand::thread_rng(),
+        secret_share,
+        dealers,
+        new_num_participants,
+        new_threshold,
+    )
+}
+
+/// Same as `reshare_deal`, drawing the coefficients from the given RNG.
+pub fn reshare_deal_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret_share: &SecretShare,
+    dealers: &[ParticipantIndex],
+    new_num_participants: u32,
+    new_threshold: u32,
+) -> Result<(ReshareDealing, Vec<SecretShare>), NoistError> {
+    // Weighted share (w_i) is = λ_i * s_i, so that the dealers' weighted shares sum to the secret.
+    let lambda = lagrance_interpolating_value(dealers, secret_share.index)?;
+    let weighted_share = lambda * secret_share.secret.expose();
+
+    let (shares, _, vss_commitments) =
+        secret_share_gen_with_rng(rng, weighted_share, new_num_participants, new_threshold)?;
+
+    Ok((
+        ReshareDealing {
+            dealer: secret_share.index,
+            vss_commitments,
+        },
+        shares,
+    ))
+}
+
+pub fn reshare_verify_dealing(
+    dealing: &ReshareDealing,
+    dealers: &[ParticipantIndex],
+    old_vss_commitments: &VssCommitment,
+) -> bool {
+    // Check if the dealing constant (W_i) equals to λ_i * P_i from the old commitments.
+    let public_share = match vss_public_share(dealing.dealer, old_vss_commitments) {
+        Ok(public_share) => public_share,
+        Err(_) => return false,
+    };
+
+    match lagrance_interpolating_value(dealers, dealing.dealer) {
+        Ok(lambda) => dealing.vss_commitments.constant() == lambda * public_share.point,
+        Err(_) => false,
+    }
+}
+
+/// Final step, run by each new participant over the dealings of the agreed `dealers`.
+///
+/// Dealers are the same set passed to `reshare_deal`, of at least the old threshold, so that their
+/// interpolating values (λ_i) sum the weighted shares to the secret.
+pub fn reshare_finalize(
+    index: ParticipantIndex,
+    dealers: &[ParticipantIndex],
+    dealings: &[(ReshareDealing, SecretShare)],
+    old_vss_commitments: &VssCommitment,
+    new_threshold: u32,
+) -> Result<KeyPackage, NoistError> {
+    // Dealings must come from exactly the agreed dealers, each counted only once.
+    let dealer_indexes: Vec<ParticipantIndex> =
+        dealings.iter().map(|(dealing, _)| dealing.dealer).collect();
+
+    validate_dealer_set(
+        dealers,
+        &dealer_indexes,
+        old_vss_commitments.threshold() as u32,
+    )?;
+
+    let mut secret_share = MaybeScalar::Zero;
+
+    for (dealing, share) in dealings {
+        if dealing.vss_commitments.threshold() != new_threshold as usize {
+            return Err(NoistError::ThresholdMismatch {
+                expected: new_threshold as usize,
+                found: dealing.vss_commitments.threshold(),
+            });
+        }
+
+        if !reshare_verify_dealing(dealing, dealers, old_vss_commitments) {
+            return Err(NoistError::InvalidProof(dealing.dealer));
+        }
+
+        // The share must be addressed to this participant and match the dealer's commitments.
+        if share.index != index {
+            return Err(NoistError::IndexMismatch {
+                expected: index,
+                found: share.index,
+            });
+        }
+
+        if !vss_verify_secret(share, &dealing.vss_commitments) {
+            return Err(NoistError::VssMismatch(dealing.dealer));
+        }
+
+        secret_share += share.secret.expose();
+    }
+
+    let secret_share = match secret_share {
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => SecretShare::new(index, scalar),
+    };
+
+    // New commitments are the sum of every dealer's commitments.
+    let vss_commitments = vss_combine(
+        &dealings
+            .iter()
+            .map(|(dealing, _)| &dealing.vss_commitments)
+            .collect::<Vec<_>>(),
+    )?;
+
+    // Group key (P) is unchanged by resharing.
+    if vss_commitments.constant() != old_vss_commitments.constant() {
+        return Err(NoistError::VssMismatch(index));
+    }
+
+    Ok(KeyPackage::new(secret_share, vss_commitments))
+}
@@ -1,80 +1,176 @@
-use secp::{MaybePoint, MaybeScalar, Point};
+use rand::{CryptoRng, RngCore};
+use secp::{MaybePoint, MaybeScalar, Point, Scalar};
+use zeroize::Zeroizing;
 
 use crate::{
+    error::NoistError,
     hash::{tagged_hash, HashTag},
-    into::SecpError,
+    secret::random_scalar_with_rng,
+    sensitive::SecretScalar,
+    vss::multi_scalar_mul,
 };
 
 use super::into::{IntoPoint, IntoScalar};
 
 #[derive(Clone, Copy)]
 pub enum SignFlag {
+    /// BIP-340 signatures with the deterministic H(sk||m) nonce.
     BIP0340Sign,
+    /// BIP-340 signatures with the default nonce derivation using auxiliary randomness.
+    BIP0340AuxSign,
 }
 
 pub fn compute_challenge(
     public_nonce: Option<Point>,
     public_key: Option<Point>,
-    message_bytes: [u8; 32],
+    message_bytes: &[u8],
     flag: SignFlag,
-) -> Result<[u8; 32], SecpError> {
+) -> Result<[u8; 32], NoistError> {
     match flag {
-        SignFlag::BIP0340Sign => {
+        SignFlag::BIP0340Sign | SignFlag::BIP0340AuxSign => {
             // Follow BIP-340. Challenge e bytes is = H(R||P||m).
 
             let public_nonce = match public_nonce {
-                None => return Err(SecpError::InvalidPoint),
+                None => return Err(NoistError::InvalidPoint),
                 Some(point) => point,
             };
 
             let public_key = match public_key {
-                None => return Err(SecpError::InvalidPoint),
+                None => return Err(NoistError::InvalidPoint),
                 Some(point) => point,
             };
 
-            let mut challenge_preimage = Vec::<u8>::with_capacity(96);
+            let mut challenge_preimage = Vec::<u8>::with_capacity(64 + message_bytes.len());
             challenge_preimage.extend(public_nonce.serialize_xonly());
             challenge_preimage.extend(public_key.serialize_xonly());
             challenge_preimage.extend(message_bytes);
-            return Ok(tagged_hash(challenge_preimage, HashTag::BIP0340Challenge));
+            Ok(tagged_hash(challenge_preimage, HashTag::BIP0340Challenge))
         }
-    };
+    }
 }
 
-fn deterministic_nonce(secret_key: [u8; 32], message: [u8; 32]) -> [u8; 32] {
-    let mut secret_nonce_preimage = Vec::<u8>::new();
+/// Challenge for Schnorr proofs of knowledge outside of BIP-340, over compressed points so that
+/// no parity adjustment is needed. Challenge (c) is = int(H_tag(R||P||m)) mod n.
+pub fn compute_proof_challenge(
+    public_nonce: Point,
+    public_key: Point,
+    message_bytes: &[u8],
+    tag: HashTag,
+) -> Result<Scalar, NoistError> {
+    let mut challenge_preimage = Vec::<u8>::with_capacity(66 + message_bytes.len());
+    challenge_preimage.extend(public_nonce.serialize());
+    challenge_preimage.extend(public_key.serialize());
+    challenge_preimage.extend(message_bytes);
+
+    match MaybeScalar::reduce_from(&tagged_hash(challenge_preimage, tag)) {
+        MaybeScalar::Zero => Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => Ok(scalar),
+    }
+}
+
+fn deterministic_nonce(secret_key: [u8; 32], message: &[u8]) -> Zeroizing<[u8; 32]> {
+    let mut secret_nonce_preimage = Zeroizing::new(Vec::<u8>::with_capacity(32 + message.len()));
 
     secret_nonce_preimage.extend(secret_key);
     secret_nonce_preimage.extend(message);
 
-    tagged_hash(secret_nonce_preimage, HashTag::DeterministicNonce)
+    Zeroizing::new(tagged_hash(
+        &*secret_nonce_preimage,
+        HashTag::DeterministicNonce,
+    ))
+}
+
+fn bip340_nonce(
+    secret_key: Scalar,
+    public_key: Point,
+    message: &[u8],
+    aux_rand: [u8; 32],
+) -> Zeroizing<[u8; 32]> {
+    // Masked key (t) is = bytes(d) xor H_aux(a).
+    let aux_hash = tagged_hash(aux_rand, HashTag::BIP0340Aux);
+
+    let mut masked_key = Zeroizing::new(secret_key.serialize());
+    for (byte, mask) in masked_key.iter_mut().zip(aux_hash.iter()) {
+        *byte ^= mask;
+    }
+
+    // Secret nonce (k) bytes is = H_nonce(t||bytes(P)||m).
+    let mut secret_nonce_preimage = Zeroizing::new(Vec::<u8>::with_capacity(64 + message.len()));
+    secret_nonce_preimage.extend(*masked_key);
+    secret_nonce_preimage.extend(public_key.serialize_xonly());
+    secret_nonce_preimage.extend(message);
+
+    Zeroizing::new(tagged_hash(&*secret_nonce_preimage, HashTag::BIP0340Nonce))
 }
 
 pub fn sign_schnorr(
     secret_key_bytes: [u8; 32],
     message_bytes: [u8; 32],
     flag: SignFlag,
-) -> Result<[u8; 64], SecpError> {
+) -> Result<[u8; 64], NoistError> {
+    sign_schnorr_message(secret_key_bytes, &message_bytes, flag)
+}
+
+/// Same as `sign_schnorr`, for messages of arbitrary length.
+pub fn sign_schnorr_message(
+    secret_key_bytes: [u8; 32],
+    message_bytes: &[u8],
+    flag: SignFlag,
+) -> Result<[u8; 64], NoistError> {
+    // Fresh auxiliary randomness (a) is only drawn when the signing method uses it.
+    let mut aux_rand = [0u8; 32];
+
+    if let SignFlag::BIP0340AuxSign = flag {
+        rand::thread_rng()
+            .try_fill_bytes(&mut aux_rand)
+            .map_err(|_| NoistError::RngFailure)?;
+    }
+
+    sign_schnorr_message_aux(secret_key_bytes, message_bytes, aux_rand, flag)
+}
+
+/// Same as `sign_schnorr_message`, with caller-provided auxiliary randomness.
+/// Auxiliary randomness is only used by `SignFlag::BIP0340AuxSign`.
+pub fn sign_schnorr_message_aux(
+    secret_key_bytes: [u8; 32],
+    message_bytes: &[u8],
+    aux_rand: [u8; 32],
+    flag: SignFlag,
+) -> Result<[u8; 64], NoistError> {
     // Check if the secret key (d) is a valid scalar.
-    let mut secret_key = secret_key_bytes.into_scalar()?;
+    let secret_key = SecretScalar::new(secret_key_bytes.into_scalar()?);
 
     // Public key (P) is = dG.
-    let public_key = secret_key.base_point_mul();
+    let public_key = secret_key.expose().base_point_mul();
 
-    // Negate the secret key (d) if it has odd public key.
-    secret_key = secret_key.negate_if(public_key.parity());
+    // Negate the secret key (d) if it has odd public key, without branching on it.
+    let secret_key = SecretScalar::new(secret_key.expose().negate_if(public_key.parity()));
 
-    // Nonce generation is deterministic. Secret nonce (k) is = H(sk||m).
-    let secret_nonce_bytes = deterministic_nonce(secret_key_bytes, message_bytes);
+    let secret_nonce = SecretScalar::new(match flag {
+        SignFlag::BIP0340Sign => {
+            // Nonce generation is deterministic. Secret nonce (k) is = H(sk||m).
+            let secret_nonce_bytes = deterministic_nonce(secret_key_bytes, message_bytes);
 
-    // Check if the secret nonce (k) is a valid scalar.
-    let mut secret_nonce = secret_nonce_bytes.into_scalar()?;
+            // Check if the secret nonce (k) is a valid scalar.
+            secret_nonce_bytes.into_scalar()?
+        }
+        SignFlag::BIP0340AuxSign => {
+            // Follow BIP-340. Secret nonce (k) is = int(H_nonce(t||P||m)) mod n.
+            let secret_nonce_bytes =
+                bip340_nonce(secret_key.expose(), public_key, message_bytes, aux_rand);
+
+            match MaybeScalar::reduce_from(&secret_nonce_bytes) {
+                MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+                MaybeScalar::Valid(scalar) => scalar,
+            }
+        }
+    });
 
     // Public nonce (R) is = kG.
-    let public_nonce = secret_nonce.base_point_mul();
+    let public_nonce = secret_nonce.expose().base_point_mul();
 
     // Negate the secret nonce (k) if it has odd public nonce.
-    secret_nonce = secret_nonce.negate_if(public_nonce.parity());
+    let secret_nonce = SecretScalar::new(secret_nonce.expose().negate_if(public_nonce.parity()));
 
     // Compute the challenge (e) bytes depending on the signing method.
     let challenge_array: [u8; 32] =
@@ -84,8 +180,8 @@ pub fn sign_schnorr(
     let challenge = challenge_array.into_scalar()?;
 
     // Commitment (s) is = k + ed mod n.
-    let commitment = match secret_nonce + challenge * secret_key {
-        MaybeScalar::Zero => return Err(SecpError::InvalidScalar),
+    let commitment = match secret_nonce.expose() + challenge * secret_key.expose() {
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
         MaybeScalar::Valid(scalar) => scalar,
     };
 
@@ -101,7 +197,10 @@ pub fn sign_schnorr(
     // Signature is = bytes(R) || bytes((k + ed) mod n).
     signature
         .try_into()
-        .map_err(|_| SecpError::SignatureParseError)
+        .map_err(|signature: Vec<u8>| NoistError::InvalidLength {
+            expected: 64,
+            found: signature.len(),
+        })
 }
 
 pub fn verify_schnorr(
@@ -109,17 +208,20 @@ pub fn verify_schnorr(
     message_bytes: [u8; 32],
     signature_bytes: [u8; 64],
     flag: SignFlag,
-) -> Result<(), SecpError> {
-    // Check if the public key (P) is a valid point.
-    let public_key = public_key_bytes.into_point()?;
-
-    // Parse public nonce (R) bytes.
-    let public_nonce_bytes: [u8; 32] = (&signature_bytes[0..32])
-        .try_into()
-        .map_err(|_| SecpError::SignatureParseError)?;
+) -> Result<(), NoistError> {
+    verify_schnorr_message(public_key_bytes, &message_bytes, signature_bytes, flag)
+}
 
-    // Check if the public nonce (R) is a valid point.
-    let public_nonce = public_nonce_bytes.into_point()?;
+/// Same as `verify_schnorr`, for messages of arbitrary length.
+pub fn verify_schnorr_message(
+    public_key_bytes: [u8; 32],
+    message_bytes: &[u8],
+    signature_bytes: [u8; 64],
+    flag: SignFlag,
+) -> Result<(), NoistError> {
+    // Parse public key (P), public nonce (R) and commitment (s).
+    let (public_key, public_nonce, commitment) =
+        parse_schnorr(&public_key_bytes, &signature_bytes)?;
 
     // Compute the challenge (e) bytes depending on the signing method.
     let challange_array: [u8; 32] =
@@ -128,25 +230,107 @@ pub fn verify_schnorr(
     // Challange (e) is = int(challange_bytes) mod n.
     let challange = challange_array.into_scalar()?;
 
-    // Parse commitment (s) bytes.
-    let commitment_bytes: [u8; 32] = (&signature_bytes[32..64])
-        .try_into()
-        .map_err(|_| SecpError::SignatureParseError)?;
-
-    // Check if commitment (s) is a valid scalar.
-    let commitment = commitment_bytes.into_scalar()?;
-
     // Check if the equation (R + eP) is a valid point.
     let equation = match public_nonce + challange * public_key {
         MaybePoint::Infinity => {
-            return Err(SecpError::InvalidPoint);
+            return Err(NoistError::InvalidPoint);
         }
         MaybePoint::Valid(point) => point,
     };
 
     // Check if the equation (R + eP) equals to sG.
     match commitment.base_point_mul() == equation {
-        false => return Err(SecpError::InvalidSignature),
-        true => return Ok(()),
+        false => Err(NoistError::InvalidSignature),
+        true => Ok(()),
+    }
+}
+
+/// Public key, message of arbitrary length and signature to be verified in a batch.
+pub type SchnorrBatchItem<'a> = ([u8; 32], &'a [u8], [u8; 64]);
+
+pub fn verify_schnorr_batch(items: &[SchnorrBatchItem], flag: SignFlag) -> Result<(), NoistError> {
+    verify_schnorr_batch_with_rng(&mut rand::thread_rng(), items, flag)
+}
+
+/// Same as `verify_schnorr_batch`, drawing the batch weights from the given RNG.
+#[allow(non_snake_case)]
+pub fn verify_schnorr_batch_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    items: &[SchnorrBatchItem],
+    flag: SignFlag,
+) -> Result<(), NoistError> {
+    // Batch equation is (sum(a_i * s_i))G = sum(a_i * R_i) + sum((a_i * e_i) * P_i), checked as
+    // a single multi-scalar multiplication over 2n+1 terms that must sum to infinity.
+    let mut lhs = MaybeScalar::Zero;
+    let mut terms = Vec::<(Point, MaybeScalar)>::with_capacity(2 * items.len() + 1);
+
+    for (position, (public_key_bytes, message_bytes, signature_bytes)) in items.iter().enumerate() {
+        let (P_i, R_i, s_i) = match parse_schnorr(public_key_bytes, signature_bytes) {
+            Ok(parsed) => parsed,
+            Err(_) => return Err(schnorr_batch_blame(items, flag)),
+        };
+
+        // Challange (e_i) is = int(challange_bytes) mod n.
+        let e_i = compute_challenge(Some(R_i), Some(P_i), message_bytes, flag)?.into_scalar()?;
+
+        // First weight is one, the rest are random so that invalid signatures cannot cancel out.
+        let a_i = match position {
+            0 => Scalar::one(),
+            _ => random_scalar_with_rng(rng)?,
+        };
+
+        lhs += a_i * s_i;
+        terms.push((R_i, MaybeScalar::Valid(a_i)));
+        terms.push((P_i, MaybeScalar::Valid(a_i * e_i)));
+    }
+
+    terms.push((Point::generator(), -lhs));
+
+    match multi_scalar_mul(&terms) == MaybePoint::Infinity {
+        true => Ok(()),
+        false => Err(schnorr_batch_blame(items, flag)),
     }
 }
+
+fn parse_schnorr(
+    public_key_bytes: &[u8; 32],
+    signature_bytes: &[u8; 64],
+) -> Result<(Point, Point, Scalar), NoistError> {
+    // Check if the public key (P) is a valid point.
+    let public_key = public_key_bytes.into_point()?;
+
+    // Check if the public nonce (R) is a valid point.
+    let public_nonce_bytes: [u8; 32] = signature_bytes[0..32]
+        .try_into()
+        .map_err(|_| NoistError::ParseError { offset: 0 })?;
+
+    let public_nonce = public_nonce_bytes
+        .into_point()
+        .map_err(|_| NoistError::ParseError { offset: 0 })?;
+
+    // Check if commitment (s) is a valid scalar.
+    let commitment_bytes: [u8; 32] = signature_bytes[32..64]
+        .try_into()
+        .map_err(|_| NoistError::ParseError { offset: 32 })?;
+
+    let commitment = commitment_bytes
+        .into_scalar()
+        .map_err(|_| NoistError::ParseError { offset: 32 })?;
+
+    Ok((public_key, public_nonce, commitment))
+}
+
+fn schnorr_batch_blame(items: &[SchnorrBatchItem], flag: SignFlag) -> NoistError {
+    // Fall back to verifying one by one to find the offending entries.
+    let invalid: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, (public_key_bytes, message_bytes, signature_bytes))| {
+            verify_schnorr_message(*public_key_bytes, message_bytes, *signature_bytes, flag)
+                .is_err()
+        })
+        .map(|(position, _)| position)
+        .collect();
+
+    NoistError::InvalidSignatures(invalid)
+}
@@ -1,81 +1,132 @@
-use rand::RngCore;
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use secp::{MaybeScalar, Point, Scalar};
+use zeroize::Zeroizing;
 
-use crate::{into::SecpError, lagrance::lagrance_interpolating_value, vss::vss_commit};
+use crate::{
+    error::NoistError,
+    lagrance::lagrance_interpolating_value_unchecked,
+    sensitive::SecretPolynomial,
+    share::{validate_indexes, ParticipantIndex, SecretShare},
+    vss::{vss_commit, VssCommitment},
+};
 
 pub fn secret_share_gen(
     secret_key: Scalar,
-    num_participants: u8,
-    threshold: u8,
-) -> Result<(Vec<(Scalar, Scalar)>, Point, Vec<Point>), SecpError> {
-    // Generate random coefficients for the polynomial.
-    let mut coefficients = Vec::<Scalar>::new();
-
-    for _ in 0..threshold - 1 {
-        let mut rng = rand::thread_rng();
-        let mut coeff_bytes: Vec<u8> = vec![0; 32];
-
-        match rng.try_fill_bytes(&mut coeff_bytes[..]) {
-            Ok(_) => (),
-            Err(_) => return Err(SecpError::InvalidScalar),
-        };
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(Vec<SecretShare>, Point, VssCommitment), NoistError> {
+    secret_share_gen_with_rng(
+        &mut rand::thread_rng(),
+        secret_key,
+        num_participants,
+        threshold,
+    )
+}
 
-        let coeff = match Scalar::from_slice(&coeff_bytes) {
-            Ok(scalar) => scalar,
-            Err(_) => return Err(SecpError::InvalidScalar),
-        };
+/// Same as `secret_share_gen`, drawing the coefficients from the given RNG.
+pub fn secret_share_gen_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret_key: Scalar,
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(Vec<SecretShare>, Point, VssCommitment), NoistError> {
+    if threshold == 0 {
+        return Err(NoistError::ZeroThreshold);
+    }
 
-        coefficients.push(coeff);
+    if threshold > num_participants {
+        return Err(NoistError::ThresholdExceedsParticipants {
+            threshold,
+            num_participants,
+        });
     }
 
-    let (participant_private_keys, coefficients) =
-        secret_share_shard(secret_key, &coefficients, num_participants)?;
+    // Generate random coefficients for the polynomial.
+    let polynomial = SecretPolynomial::random_with_rng(rng, secret_key, threshold as usize - 1)?;
+
+    let participant_private_keys = secret_share_shard_polynomial(&polynomial, num_participants)?;
 
-    let vss_commitments = vss_commit(&coefficients)?;
+    let vss_commitments = vss_commit(&polynomial)?;
 
     Ok((
         participant_private_keys,
-        vss_commitments[0],
+        vss_commitments.constant(),
         vss_commitments,
     ))
 }
 
+/// Draws after which `random_scalar` gives up on a source that keeps producing invalid scalars.
+pub const RANDOM_SCALAR_ATTEMPTS: usize = 64;
+
+pub fn random_scalar() -> Result<Scalar, NoistError> {
+    random_scalar_with_rng(&mut rand::thread_rng())
+}
+
+/// Same as `random_scalar`, drawing from the given RNG.
+pub fn random_scalar_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Result<Scalar, NoistError> {
+    let mut scalar_bytes = Zeroizing::new([0u8; 32]);
+
+    // Rejection sampling: zero and values at or above the curve order are redrawn, so every
+    // non-zero scalar is equally likely. A fair source is rejected with probability ~2^-128.
+    for _ in 0..RANDOM_SCALAR_ATTEMPTS {
+        match rng.try_fill_bytes(&mut scalar_bytes[..]) {
+            Ok(_) => (),
+            Err(_) => return Err(NoistError::RngFailure),
+        };
+
+        if let Ok(scalar) = Scalar::from_slice(&scalar_bytes[..]) {
+            return Ok(scalar);
+        }
+    }
+
+    // Repeated rejections mean the source is broken (e.g. stuck at zero).
+    Err(NoistError::RngFailure)
+}
+
+/// Seeded RNG for reproducible dealings and test vectors. Never use a fixed seed for real keys.
+pub fn deterministic_rng(seed: [u8; 32]) -> ChaCha20Rng {
+    ChaCha20Rng::from_seed(seed)
+}
+
 pub fn secret_share_shard(
     s: Scalar,
-    coefficients: &Vec<Scalar>,
-    num_shares: u8,
-) -> Result<(Vec<(Scalar, Scalar)>, Vec<Scalar>), SecpError> {
+    coefficients: &[Scalar],
+    num_shares: u32,
+) -> Result<(Vec<SecretShare>, SecretPolynomial), NoistError> {
     // Prepend the secret to the coefficients
-    let mut coefficients_full = Vec::<Scalar>::new();
-    coefficients_full.push(s);
-    coefficients_full.extend(coefficients);
+    let polynomial = SecretPolynomial::from_coefficients(s, coefficients);
 
+    let secret_key_shares = secret_share_shard_polynomial(&polynomial, num_shares)?;
+
+    Ok((secret_key_shares, polynomial))
+}
+
+/// Same as `secret_share_shard`, for a polynomial that already holds the secret as a_0.
+pub fn secret_share_shard_polynomial(
+    polynomial: &SecretPolynomial,
+    num_shares: u32,
+) -> Result<Vec<SecretShare>, NoistError> {
     // Evaluate the polynomial for each point x=1,...,n
-    let mut secret_key_shares = Vec::<(Scalar, Scalar)>::new();
+    let mut secret_key_shares = Vec::<SecretShare>::with_capacity(num_shares as usize);
 
     for x_i in 1..=num_shares {
-        let mut x_i_scalar_bytes = vec![0; 31];
-        x_i_scalar_bytes.push(x_i);
+        let x_i = ParticipantIndex::new(x_i)?;
 
-        let x_i_scalar = match Scalar::from_slice(&x_i_scalar_bytes) {
-            Ok(scalar) => scalar,
-            Err(_) => return Err(SecpError::InvalidScalar),
-        };
+        let y_i_scalar = polynomial_evaluate(x_i.to_scalar(), polynomial)?;
 
-        let y_i_scalar = polynomial_evaluate(x_i_scalar, &coefficients_full)?;
-
-        secret_key_shares.push((x_i_scalar, y_i_scalar));
+        secret_key_shares.push(SecretShare::new(x_i, y_i_scalar));
     }
 
-    Ok((secret_key_shares, coefficients_full))
+    Ok(secret_key_shares)
 }
 
-pub fn secret_share_combine(
-    shares: &Vec<(Scalar, Scalar)>,
-    threshold: usize,
-) -> Result<Scalar, SecpError> {
-    if shares.len() < threshold {
-        return Err(SecpError::InvalidScalar);
+pub fn secret_share_combine(shares: &[SecretShare], threshold: u32) -> Result<Scalar, NoistError> {
+    if shares.len() < threshold as usize {
+        return Err(NoistError::InsufficientShares {
+            required: threshold as usize,
+            provided: shares.len(),
+        });
     }
 
     let s = polynomial_interpolate_constant(shares)?;
@@ -83,39 +134,40 @@ pub fn secret_share_combine(
     Ok(s)
 }
 
-fn polynomial_evaluate(x: Scalar, coeffs: &Vec<Scalar>) -> Result<Scalar, SecpError> {
+fn polynomial_evaluate(x: Scalar, coeffs: &[Scalar]) -> Result<Scalar, NoistError> {
     let mut value = MaybeScalar::Zero;
 
-    let mut reversed_coeffs = coeffs.clone();
-    reversed_coeffs.reverse();
-
-    for coeff in reversed_coeffs {
-        value = value * x;
-        value = value + coeff;
+    // Horner's method, from the highest coefficient down, without copying the coefficients.
+    for coeff in coeffs.iter().rev() {
+        value *= x;
+        value += *coeff;
     }
 
     Ok(match value {
         MaybeScalar::Valid(scalar) => scalar,
-        MaybeScalar::Zero => return Err(SecpError::InvalidScalar),
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
     })
 }
 
-fn polynomial_interpolate_constant(points: &Vec<(Scalar, Scalar)>) -> Result<Scalar, SecpError> {
-    let mut x_coords = Vec::<Scalar>::new();
+fn polynomial_interpolate_constant(points: &[SecretShare]) -> Result<Scalar, NoistError> {
+    let mut x_coords = Vec::<ParticipantIndex>::new();
 
     for point in points {
-        x_coords.push(point.0);
+        x_coords.push(point.index);
     }
 
+    validate_indexes(&x_coords, None)?;
+
     let mut f_zero: MaybeScalar = MaybeScalar::Zero;
 
     for point in points {
-        let delta = point.1 * lagrance_interpolating_value(&x_coords, point.0)?;
+        let delta =
+            point.secret.expose() * lagrance_interpolating_value_unchecked(&x_coords, point.index)?;
         f_zero += delta;
     }
 
     Ok(match f_zero {
         MaybeScalar::Valid(scalar) => scalar,
-        MaybeScalar::Zero => return Err(SecpError::InvalidScalar),
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
     })
 }
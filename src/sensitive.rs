@@ -0,0 +1,148 @@
+use std::{fmt, ops::Deref, ptr, sync::atomic};
+
+use rand::{CryptoRng, RngCore};
+use secp::Scalar;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{error::NoistError, secret::random_scalar_with_rng};
+
+/// Overwrites a scalar in place with a public constant, in a way the compiler cannot elide.
+///
+/// `Scalar` has no `Zeroize` impl and must stay non-zero, so this mirrors what `zeroize` does for
+/// its own types: a volatile write followed by a compiler fence.
+pub(crate) fn wipe_scalar(scalar: &mut Scalar) {
+    // Scalars are non-zero, so one is the closest there is to zeroing.
+    //
+    // SAFETY: `scalar` comes from a `&mut`, so it is valid, aligned and exclusively borrowed.
+    // `Scalar` is plain data without `Drop`, so overwriting the old value without dropping it
+    // leaks nothing, and `Scalar::one()` upholds the non-zero invariant.
+    unsafe { ptr::write_volatile(scalar, Scalar::one()) };
+
+    // Keeps later accesses from being reordered before the wipe.
+    atomic::compiler_fence(atomic::Ordering::SeqCst);
+}
+
+/// Secret scalar (e.g. a key or nonce) wiped on drop and redacted in `Debug`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretScalar(Scalar);
+
+impl SecretScalar {
+    pub fn new(scalar: Scalar) -> SecretScalar {
+        SecretScalar(scalar)
+    }
+
+    pub fn random() -> Result<SecretScalar, NoistError> {
+        SecretScalar::random_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Same as `random`, drawing from the given RNG.
+    pub fn random_with_rng<R: RngCore + CryptoRng>(
+        rng: &mut R,
+    ) -> Result<SecretScalar, NoistError> {
+        Ok(SecretScalar(random_scalar_with_rng(rng)?))
+    }
+
+    /// Copy of the inner scalar. Copies are not wiped, so keep them short-lived.
+    pub fn expose(&self) -> Scalar {
+        self.0
+    }
+}
+
+impl From<Scalar> for SecretScalar {
+    fn from(scalar: Scalar) -> SecretScalar {
+        SecretScalar::new(scalar)
+    }
+}
+
+impl Zeroize for SecretScalar {
+    fn zeroize(&mut self) {
+        wipe_scalar(&mut self.0);
+    }
+}
+
+impl Drop for SecretScalar {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretScalar {}
+
+impl fmt::Debug for SecretScalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretScalar(<redacted>)")
+    }
+}
+
+/// Secret polynomial coefficients (a_0, ..., a_t-1), wiped on drop and redacted in `Debug`.
+#[derive(Clone)]
+pub struct SecretPolynomial(Vec<Scalar>);
+
+impl SecretPolynomial {
+    /// Constant term (a_0) followed by `count` random coefficients.
+    pub fn random(constant: Scalar, count: usize) -> Result<SecretPolynomial, NoistError> {
+        SecretPolynomial::random_with_rng(&mut rand::thread_rng(), constant, count)
+    }
+
+    /// Same as `random`, drawing the coefficients from the given RNG.
+    pub fn random_with_rng<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        constant: Scalar,
+        count: usize,
+    ) -> Result<SecretPolynomial, NoistError> {
+        // Capacity is reserved upfront so that growing never leaves unwiped copies behind.
+        let mut coefficients = Vec::<Scalar>::with_capacity(count + 1);
+        coefficients.push(constant);
+
+        let mut polynomial = SecretPolynomial(coefficients);
+
+        for _ in 0..count {
+            polynomial.0.push(random_scalar_with_rng(rng)?);
+        }
+
+        Ok(polynomial)
+    }
+
+    /// Constant term (a_0) followed by the given coefficients.
+    pub fn from_coefficients(constant: Scalar, coefficients: &[Scalar]) -> SecretPolynomial {
+        let mut coefficients_full = Vec::<Scalar>::with_capacity(coefficients.len() + 1);
+        coefficients_full.push(constant);
+        coefficients_full.extend(coefficients);
+
+        SecretPolynomial(coefficients_full)
+    }
+
+    pub fn coefficients(&self) -> &[Scalar] {
+        &self.0
+    }
+}
+
+impl Deref for SecretPolynomial {
+    type Target = [Scalar];
+
+    fn deref(&self) -> &[Scalar] {
+        &self.0
+    }
+}
+
+impl Zeroize for SecretPolynomial {
+    fn zeroize(&mut self) {
+        for coefficient in self.0.iter_mut() {
+            wipe_scalar(coefficient);
+        }
+    }
+}
+
+impl Drop for SecretPolynomial {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretPolynomial {}
+
+impl fmt::Debug for SecretPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretPolynomial(<{} redacted>)", self.0.len())
+    }
+}
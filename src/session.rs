@@ -0,0 +1,283 @@
+use std::collections::BTreeSet;
+
+use secp::{MaybePoint, MaybeScalar, Point, Scalar};
+
+use crate::{
+    error::NoistError,
+    into::IntoScalar,
+    lagrance::lagrance_interpolating_value_unchecked,
+    nonce::{NonceEntry, SlotNonce},
+    schnorr::{compute_challenge, SignFlag},
+    share::{validate_indexes, ParticipantIndex, PublicShare, SecretShare},
+    tweak::TweakedGroupKey,
+    vss::{vss_public_share, VssCommitment},
+};
+
+/// Partial signature (i, s_i) produced by a single signer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartialSignature {
+    pub index: ParticipantIndex,
+    pub sig: Scalar,
+}
+
+pub fn partial_sign(
+    group_key: Point,
+    secret_share: &SecretShare,
+    nonce: &NonceEntry,
+    message_bytes: &[u8],
+    flag: SignFlag,
+) -> Result<PartialSignature, NoistError> {
+    // Secret share and nonce share must belong to the same participant.
+    if secret_share.index != nonce.nonce_share.index() {
+        return Err(NoistError::IndexMismatch {
+            expected: secret_share.index,
+            found: nonce.nonce_share.index(),
+        });
+    }
+
+    // Group nonce (R) is = D + ρE, bound to the message.
+    let group_nonce = nonce.slot_nonce.group_nonce(message_bytes)?;
+
+    // Nonce share (k_i) is = d_i + ρe_i.
+    let nonce_share = nonce.signing_nonce_share(message_bytes)?;
+
+    // Negate the secret share (d_i) if the group key (P) has odd y.
+    let sk_i = secret_share.secret.expose().negate_if(group_key.parity());
+
+    // Negate the nonce share (k_i) if the group nonce (R) has odd y.
+    let k_i = nonce_share.secret.expose().negate_if(group_nonce.parity());
+
+    // Compute the challenge (e) bytes depending on the signing method.
+    let challenge_array: [u8; 32] =
+        compute_challenge(Some(group_nonce), Some(group_key), message_bytes, flag)?;
+
+    // Challange (e) is = int(challange_bytes) mod n.
+    let challenge = challenge_array.into_scalar()?;
+
+    // Partial commitment (s_i) is = k_i + ed_i mod n.
+    let partial_commitment = match k_i + challenge * sk_i {
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => scalar,
+    };
+
+    Ok(PartialSignature {
+        index: secret_share.index,
+        sig: partial_commitment,
+    })
+}
+
+/// Same as `partial_sign`, producing a partial signature under the tweaked output key.
+pub fn partial_sign_tweaked(
+    tweaked_key: &TweakedGroupKey,
+    secret_share: &SecretShare,
+    nonce: &NonceEntry,
+    message_bytes: &[u8],
+    flag: SignFlag,
+) -> Result<PartialSignature, NoistError> {
+    // Secret share (d_i) is negated by the accumulated sign (g) before signing under Q.
+    partial_sign(
+        tweaked_key.output_key(),
+        &tweaked_key.tweak_secret_share(secret_share),
+        nonce,
+        message_bytes,
+        flag,
+    )
+}
+
+pub fn partial_sig_aggregate(
+    slot_nonce: &SlotNonce,
+    partial_sigs: &[PartialSignature],
+    message_bytes: &[u8],
+    threshold: u32,
+) -> Result<[u8; 64], NoistError> {
+    // Group nonce (R) is = D + ρE, bound to the message.
+    let group_nonce = slot_nonce.group_nonce(message_bytes)?;
+
+    partial_sig_finalize(group_nonce, partial_sigs, threshold, MaybeScalar::Zero)
+}
+
+/// Same as `partial_sig_aggregate`, producing a signature valid under the tweaked output key.
+pub fn partial_sig_aggregate_tweaked(
+    tweaked_key: &TweakedGroupKey,
+    slot_nonce: &SlotNonce,
+    partial_sigs: &[PartialSignature],
+    message_bytes: &[u8],
+    threshold: u32,
+    flag: SignFlag,
+) -> Result<[u8; 64], NoistError> {
+    let output_key = tweaked_key.output_key();
+
+    // Group nonce (R) is = D + ρE, bound to the message.
+    let group_nonce = slot_nonce.group_nonce(message_bytes)?;
+
+    // Compute the challenge (e) bytes depending on the signing method.
+    let challenge_array: [u8; 32] =
+        compute_challenge(Some(group_nonce), Some(output_key), message_bytes, flag)?;
+
+    // Challange (e) is = int(challange_bytes) mod n.
+    let challenge = challenge_array.into_scalar()?;
+
+    // Tweak term is = e * t, negated if the output key (Q) has odd y.
+    let tweak_term = (challenge * tweaked_key.tweak()).negate_if(output_key.parity());
+
+    partial_sig_finalize(group_nonce, partial_sigs, threshold, tweak_term)
+}
+
+fn partial_sig_finalize(
+    group_nonce: Point,
+    partial_sigs: &[PartialSignature],
+    threshold: u32,
+    tweak_term: MaybeScalar,
+) -> Result<[u8; 64], NoistError> {
+    if partial_sigs.len() < threshold as usize {
+        return Err(NoistError::InsufficientShares {
+            required: threshold as usize,
+            provided: partial_sigs.len(),
+        });
+    }
+
+    // Only the first t partial signatures are needed to interpolate.
+    let partial_sigs = &partial_sigs[..threshold as usize];
+
+    let x_coords: Vec<ParticipantIndex> = partial_sigs.iter().map(|sig| sig.index).collect();
+
+    validate_indexes(&x_coords, None)?;
+
+    // Commitment (s) is = sum(λ_i * s_i) + et mod n.
+    let mut commitment = tweak_term;
+
+    for partial_sig in partial_sigs {
+        commitment +=
+            partial_sig.sig * lagrance_interpolating_value_unchecked(&x_coords, partial_sig.index)?;
+    }
+
+    let commitment = match commitment {
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => scalar,
+    };
+
+    // Initialize the signature with a capacity of 64 bytes.
+    let mut signature = Vec::<u8>::with_capacity(64);
+
+    // Add group nonce (R) 32 bytes.
+    signature.extend(group_nonce.serialize_xonly());
+
+    // Add commitment (s) 32 bytes.
+    signature.extend(commitment.serialize());
+
+    // Signature is = bytes(R) || bytes(sum(λ_i * s_i) + et mod n).
+    signature
+        .try_into()
+        .map_err(|signature: Vec<u8>| NoistError::InvalidLength {
+            expected: 64,
+            found: signature.len(),
+        })
+}
+
+#[allow(non_snake_case)]
+pub fn partial_sig_verify(
+    group_key: Point,
+    public_share: &PublicShare,
+    slot_nonce: &SlotNonce,
+    partial_sig: &PartialSignature,
+    message_bytes: &[u8],
+    flag: SignFlag,
+) -> bool {
+    // Public share and partial signature must belong to the same participant.
+    if public_share.index != partial_sig.index {
+        return false;
+    }
+
+    // Group nonce (R) is = D + ρE and public nonce share (R_i) is = D_i + ρE_i.
+    let (group_nonce, public_nonce_share) = match (
+        slot_nonce.group_nonce(message_bytes),
+        slot_nonce.public_nonce_share(partial_sig.index, message_bytes),
+    ) {
+        (Ok(group_nonce), Ok(public_nonce_share)) => (group_nonce, public_nonce_share),
+        _ => return false,
+    };
+
+    // Negate the public share (P_i) if the group key (P) has odd y.
+    let P_i = public_share.point.negate_if(group_key.parity());
+
+    // Negate the public nonce share (R_i) if the group nonce (R) has odd y.
+    let R_i = public_nonce_share.point.negate_if(group_nonce.parity());
+
+    // Compute the challenge (e) bytes depending on the signing method.
+    let challenge = match compute_challenge(Some(group_nonce), Some(group_key), message_bytes, flag)
+    {
+        Ok(challenge_array) => match challenge_array.into_scalar() {
+            Ok(scalar) => scalar,
+            Err(_) => return false,
+        },
+        Err(_) => return false,
+    };
+
+    // Check if the equation (R_i + eP_i) equals to s_iG.
+    partial_sig.sig.base_point_mul()
+        == match R_i + challenge * P_i {
+            MaybePoint::Infinity => return false,
+            MaybePoint::Valid(point) => point,
+        }
+}
+
+pub fn partial_sig_blame(
+    key_vss_commitments: &VssCommitment,
+    slot_nonce: &SlotNonce,
+    partial_sigs: &[PartialSignature],
+    message_bytes: &[u8],
+    flag: SignFlag,
+) -> Result<Vec<ParticipantIndex>, NoistError> {
+    // Group key (P) is the constant term commitment.
+    let group_key = key_vss_commitments.constant();
+
+    let mut blamed = Vec::<ParticipantIndex>::new();
+
+    for partial_sig in partial_sigs {
+        let public_share = vss_public_share(partial_sig.index, key_vss_commitments)?;
+
+        if !partial_sig_verify(
+            group_key,
+            &public_share,
+            slot_nonce,
+            partial_sig,
+            message_bytes,
+            flag,
+        ) {
+            blamed.push(partial_sig.index);
+        }
+    }
+
+    Ok(blamed)
+}
+
+pub fn partial_sig_aggregate_verified(
+    key_vss_commitments: &VssCommitment,
+    slot_nonce: &SlotNonce,
+    partial_sigs: &[PartialSignature],
+    message_bytes: &[u8],
+    threshold: u32,
+    flag: SignFlag,
+) -> Result<([u8; 64], Vec<ParticipantIndex>), NoistError> {
+    let blamed = partial_sig_blame(
+        key_vss_commitments,
+        slot_nonce,
+        partial_sigs,
+        message_bytes,
+        flag,
+    )?;
+
+    // Drop partial signatures from disruptive signers and repeats of a signer already seen,
+    // keeping the first valid one per index, and aggregate the rest.
+    let mut seen: BTreeSet<ParticipantIndex> = blamed.iter().copied().collect();
+
+    let honest_sigs: Vec<PartialSignature> = partial_sigs
+        .iter()
+        .filter(|partial_sig| seen.insert(partial_sig.index))
+        .copied()
+        .collect();
+
+    let signature = partial_sig_aggregate(slot_nonce, &honest_sigs, message_bytes, threshold)?;
+
+    Ok((signature, blamed))
+}
@@ -0,0 +1,207 @@
+use std::{collections::BTreeSet, fmt};
+
+use secp::{Point, Scalar};
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{error::NoistError, sensitive::SecretScalar};
+
+/// Index (x-coordinate) of a participant in a sharing. Never zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParticipantIndex(u32);
+
+/// Secret share (x, f(x)) held by a single participant. f(x) is wiped on drop and redacted in
+/// `Debug`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretShare {
+    pub index: ParticipantIndex,
+    pub secret: SecretScalar,
+}
+
+/// Public share (x, f(x)G) of a single participant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicShare {
+    pub index: ParticipantIndex,
+    pub point: Point,
+}
+
+impl ParticipantIndex {
+    pub fn new(index: u32) -> Result<ParticipantIndex, NoistError> {
+        match index {
+            0 => Err(NoistError::ZeroIndex),
+            _ => Ok(ParticipantIndex(index)),
+        }
+    }
+
+    /// Same as `new`, additionally checking that the index is within 1..=n.
+    pub fn new_within(index: u32, num_participants: u32) -> Result<ParticipantIndex, NoistError> {
+        if index > num_participants {
+            return Err(NoistError::IndexOutOfRange {
+                index,
+                num_participants,
+            });
+        }
+
+        ParticipantIndex::new(index)
+    }
+
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    pub fn to_scalar(&self) -> Scalar {
+        // Index is encoded as a big-endian 256-bit integer.
+        let mut index_bytes = [0u8; 32];
+        index_bytes[28..].copy_from_slice(&self.0.to_be_bytes());
+
+        Scalar::from_slice(&index_bytes).expect("non-zero index is always a valid scalar")
+    }
+
+    pub fn from_scalar(scalar: Scalar) -> Result<ParticipantIndex, NoistError> {
+        let scalar_bytes = scalar.serialize();
+
+        // Only the last four bytes may be set.
+        if scalar_bytes[..28].iter().any(|byte| *byte != 0) {
+            return Err(NoistError::InvalidScalar);
+        }
+
+        let mut index_bytes = [0u8; 4];
+        index_bytes.copy_from_slice(&scalar_bytes[28..]);
+
+        ParticipantIndex::new(u32::from_be_bytes(index_bytes))
+    }
+}
+
+impl From<ParticipantIndex> for Scalar {
+    fn from(index: ParticipantIndex) -> Scalar {
+        index.to_scalar()
+    }
+}
+
+impl TryFrom<Scalar> for ParticipantIndex {
+    type Error = NoistError;
+
+    fn try_from(scalar: Scalar) -> Result<ParticipantIndex, NoistError> {
+        ParticipantIndex::from_scalar(scalar)
+    }
+}
+
+/// Checks that indexes are unique and, if given, within 1..=n.
+pub fn validate_indexes(
+    indexes: &[ParticipantIndex],
+    num_participants: Option<u32>,
+) -> Result<(), NoistError> {
+    if let Some(num_participants) = num_participants {
+        for index in indexes {
+            if index.value() > num_participants {
+                return Err(NoistError::IndexOutOfRange {
+                    index: index.value(),
+                    num_participants,
+                });
+            }
+        }
+    }
+
+    // Duplicates end up next to each other once sorted, keeping large groups at O(n log n).
+    let mut sorted = indexes.to_vec();
+    sorted.sort_unstable();
+
+    match sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+        Some(pair) => Err(NoistError::DuplicateIndex(pair[0])),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `found` is exactly the agreed `dealers` set, of at least `threshold` dealers.
+///
+/// Participants combining different subsets would end up with incompatible results.
+pub fn validate_dealer_set(
+    dealers: &[ParticipantIndex],
+    found: &[ParticipantIndex],
+    threshold: u32,
+) -> Result<(), NoistError> {
+    if dealers.len() < threshold as usize {
+        return Err(NoistError::InsufficientShares {
+            required: threshold as usize,
+            provided: dealers.len(),
+        });
+    }
+
+    validate_indexes(dealers, None)?;
+    validate_indexes(found, None)?;
+
+    let dealer_set: BTreeSet<ParticipantIndex> = dealers.iter().copied().collect();
+    let found_set: BTreeSet<ParticipantIndex> = found.iter().copied().collect();
+
+    for index in found {
+        if !dealer_set.contains(index) {
+            return Err(NoistError::UnknownIndex(*index));
+        }
+    }
+
+    for dealer in dealers {
+        if !found_set.contains(dealer) {
+            return Err(NoistError::MissingIndex(*dealer));
+        }
+    }
+
+    Ok(())
+}
+
+impl SecretShare {
+    pub fn new(index: ParticipantIndex, secret: Scalar) -> SecretShare {
+        SecretShare {
+            index,
+            secret: SecretScalar::new(secret),
+        }
+    }
+
+    pub fn from_scalars(share: (Scalar, Scalar)) -> Result<SecretShare, NoistError> {
+        Ok(SecretShare::new(
+            ParticipantIndex::from_scalar(share.0)?,
+            share.1,
+        ))
+    }
+
+    pub fn to_scalars(&self) -> (Scalar, Scalar) {
+        (self.index.to_scalar(), self.secret.expose())
+    }
+
+    pub fn public_share(&self) -> PublicShare {
+        PublicShare::new(self.index, self.secret.expose().base_point_mul())
+    }
+}
+
+impl Zeroize for SecretShare {
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretShare {}
+
+impl fmt::Debug for SecretShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretShare")
+            .field("index", &self.index)
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+impl PublicShare {
+    pub fn new(index: ParticipantIndex, point: Point) -> PublicShare {
+        PublicShare { index, point }
+    }
+
+    pub fn from_scalar_point(share: (Scalar, Point)) -> Result<PublicShare, NoistError> {
+        Ok(PublicShare::new(
+            ParticipantIndex::from_scalar(share.0)?,
+            share.1,
+        ))
+    }
+
+    pub fn to_scalar_point(&self) -> (Scalar, Point) {
+        (self.index.to_scalar(), self.point)
+    }
+}
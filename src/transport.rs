@@ -0,0 +1,219 @@
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    XChaCha20Poly1305, XNonce,
+};
+use rand::{CryptoRng, RngCore};
+use secp::{Point, Scalar};
+use zeroize::Zeroizing;
+
+use crate::{
+    error::NoistError,
+    hash::{tagged_hash, HashTag},
+    into::IntoScalar,
+    share::{ParticipantIndex, SecretShare},
+    vse::vse_encrypting_key_secret,
+};
+
+/// Share package encrypted from a sender to a recipient within a session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SealedPackage {
+    pub sender: ParticipantIndex,
+    pub recipient: ParticipantIndex,
+    pub session_id: [u8; 32],
+    pub nonce: [u8; 24],
+    pub ciphertext: Vec<u8>,
+}
+
+/// Symmetric key is = H(ECDH(sk, PK)), the same for both ends of the channel.
+pub fn transport_key(self_secret: Scalar, to_public: Point) -> Zeroizing<[u8; 32]> {
+    let shared_secret = vse_encrypting_key_secret(self_secret, to_public);
+    let shared_secret_bytes = Zeroizing::new(shared_secret.expose().serialize());
+
+    Zeroizing::new(tagged_hash(
+        *shared_secret_bytes,
+        HashTag::ShareTransportKey,
+    ))
+}
+
+fn transport_associated_data(
+    sender: ParticipantIndex,
+    recipient: ParticipantIndex,
+    session_id: &[u8; 32],
+) -> Vec<u8> {
+    // Associated data is = sender||recipient||session_id.
+    let mut associated_data = Vec::<u8>::with_capacity(40);
+    associated_data.extend(sender.value().to_be_bytes());
+    associated_data.extend(recipient.value().to_be_bytes());
+    associated_data.extend(session_id);
+
+    associated_data
+}
+
+pub fn transport_seal(
+    key: &[u8; 32],
+    sender: ParticipantIndex,
+    recipient: ParticipantIndex,
+    session_id: [u8; 32],
+    plaintext: &[u8],
+) -> Result<SealedPackage, NoistError> {
+    transport_seal_with_rng(
+        &mut rand::thread_rng(),
+        key,
+        sender,
+        recipient,
+        session_id,
+        plaintext,
+    )
+}
+
+/// Same as `transport_seal`, drawing the nonce from the given RNG.
+pub fn transport_seal_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    key: &[u8; 32],
+    sender: ParticipantIndex,
+    recipient: ParticipantIndex,
+    session_id: [u8; 32],
+    plaintext: &[u8],
+) -> Result<SealedPackage, NoistError> {
+    // Nonces are random, which is safe with the extended 192-bit XChaCha20 nonce.
+    let mut nonce = [0u8; 24];
+    rng.try_fill_bytes(&mut nonce)
+        .map_err(|_| NoistError::RngFailure)?;
+
+    let associated_data = transport_associated_data(sender, recipient, &session_id);
+
+    let ciphertext = XChaCha20Poly1305::new(key.into())
+        .encrypt(
+            &XNonce::from(nonce),
+            Payload {
+                msg: plaintext,
+                aad: &associated_data,
+            },
+        )
+        .map_err(|_| NoistError::EncryptionFailure)?;
+
+    Ok(SealedPackage {
+        sender,
+        recipient,
+        session_id,
+        nonce,
+        ciphertext,
+    })
+}
+
+/// Plaintext is wiped on drop, as it usually holds a secret share.
+pub fn transport_open(
+    key: &[u8; 32],
+    package: &SealedPackage,
+) -> Result<Zeroizing<Vec<u8>>, NoistError> {
+    let associated_data =
+        transport_associated_data(package.sender, package.recipient, &package.session_id);
+
+    // Fails if the ciphertext, sender, recipient or session id were tampered with.
+    XChaCha20Poly1305::new(key.into())
+        .decrypt(
+            &XNonce::from(package.nonce),
+            Payload {
+                msg: &package.ciphertext,
+                aad: &associated_data,
+            },
+        )
+        .map(Zeroizing::new)
+        .map_err(|_| NoistError::DecryptionFailure)
+}
+
+/// Encrypts a secret share dealt by `sender` to the share's owner.
+pub fn transport_encrypt_share(
+    self_secret: Scalar,
+    to_public: Point,
+    sender: ParticipantIndex,
+    session_id: [u8; 32],
+    secret_share: &SecretShare,
+) -> Result<SealedPackage, NoistError> {
+    transport_encrypt_share_with_rng(
+        &mut rand::thread_rng(),
+        self_secret,
+        to_public,
+        sender,
+        session_id,
+        secret_share,
+    )
+}
+
+/// Same as `transport_encrypt_share`, drawing the nonce from the given RNG.
+pub fn transport_encrypt_share_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    self_secret: Scalar,
+    to_public: Point,
+    sender: ParticipantIndex,
+    session_id: [u8; 32],
+    secret_share: &SecretShare,
+) -> Result<SealedPackage, NoistError> {
+    // Share package is = index||secret, wiped once sealed.
+    let mut plaintext = Zeroizing::new(Vec::<u8>::with_capacity(36));
+    plaintext.extend(secret_share.index.value().to_be_bytes());
+    plaintext.extend(Zeroizing::new(secret_share.secret.expose().serialize()).iter());
+
+    transport_seal_with_rng(
+        rng,
+        &transport_key(self_secret, to_public),
+        sender,
+        secret_share.index,
+        session_id,
+        &plaintext,
+    )
+}
+
+/// Decrypts a secret share sealed to `self_index` within the expected session.
+pub fn transport_decrypt_share(
+    self_secret: Scalar,
+    from_public: Point,
+    self_index: ParticipantIndex,
+    session_id: [u8; 32],
+    package: &SealedPackage,
+) -> Result<SecretShare, NoistError> {
+    if package.recipient != self_index {
+        return Err(NoistError::IndexMismatch {
+            expected: self_index,
+            found: package.recipient,
+        });
+    }
+
+    if package.session_id != session_id {
+        return Err(NoistError::DecryptionFailure);
+    }
+
+    let plaintext = transport_open(&transport_key(self_secret, from_public), package)?;
+
+    if plaintext.len() != 36 {
+        return Err(NoistError::InvalidLength {
+            expected: 36,
+            found: plaintext.len(),
+        });
+    }
+
+    let index_bytes: [u8; 4] = plaintext[0..4]
+        .try_into()
+        .map_err(|_| NoistError::ParseError { offset: 0 })?;
+
+    let index = ParticipantIndex::new(u32::from_be_bytes(index_bytes))?;
+
+    // Share inside the package must belong to the recipient.
+    if index != self_index {
+        return Err(NoistError::IndexMismatch {
+            expected: self_index,
+            found: index,
+        });
+    }
+
+    // Secret bytes are parsed in place rather than copied out of the plaintext.
+    let secret_bytes: Zeroizing<[u8; 32]> = Zeroizing::new(
+        plaintext[4..36]
+            .try_into()
+            .map_err(|_| NoistError::ParseError { offset: 4 })?,
+    );
+
+    let secret = secret_bytes.into_scalar()?;
+
+    Ok(SecretShare::new(index, secret))
+}
@@ -0,0 +1,122 @@
+use secp::{MaybePoint, MaybeScalar, Point, Scalar};
+
+use crate::{
+    error::NoistError,
+    hash::{tagged_hash, HashTag},
+    into::IntoScalar,
+    share::{PublicShare, SecretShare},
+};
+
+/// How a tweak (t) is added to the group key (Q).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TweakMode {
+    /// Q' is = Q + tG.
+    Plain,
+    /// Q' is = with_even_y(Q) + tG, as in BIP-341.
+    XOnly,
+}
+
+/// Group key with a sequence of tweaks applied.
+///
+/// Output key (Q) is = g * P + tG, where P is the untweaked group key, g is the
+/// accumulated sign and t is the accumulated tweak.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TweakedGroupKey {
+    internal_key: Point,
+    output_key: Point,
+    tweak: MaybeScalar,
+    negated: bool,
+}
+
+impl TweakedGroupKey {
+    pub fn new(group_key: Point) -> TweakedGroupKey {
+        TweakedGroupKey {
+            internal_key: group_key,
+            output_key: group_key,
+            tweak: MaybeScalar::Zero,
+            negated: false,
+        }
+    }
+
+    /// Untweaked group key (P).
+    pub fn internal_key(&self) -> Point {
+        self.internal_key
+    }
+
+    /// Tweaked group key (Q) that signatures verify under.
+    pub fn output_key(&self) -> Point {
+        self.output_key
+    }
+
+    /// Accumulated tweak (t).
+    pub fn tweak(&self) -> MaybeScalar {
+        self.tweak
+    }
+
+    /// Whether the accumulated sign (g) is -1.
+    pub fn is_negated(&self) -> bool {
+        self.negated
+    }
+
+    pub fn apply_tweak(
+        &self,
+        tweak: Scalar,
+        mode: TweakMode,
+    ) -> Result<TweakedGroupKey, NoistError> {
+        // Sign (g) is -1 for x-only tweaks of a key with odd y, otherwise 1.
+        let negate = mode == TweakMode::XOnly && !self.output_key.has_even_y();
+
+        let (output_key, accumulated_tweak) = match negate {
+            true => (-self.output_key, -self.tweak),
+            false => (self.output_key, self.tweak),
+        };
+
+        // Output key (Q') is = gQ + tG.
+        let output_key = match output_key + tweak.base_point_mul() {
+            MaybePoint::Infinity => return Err(NoistError::InvalidPoint),
+            MaybePoint::Valid(point) => point,
+        };
+
+        Ok(TweakedGroupKey {
+            internal_key: self.internal_key,
+            output_key,
+            // Accumulated tweak (t') is = t + g * t_acc.
+            tweak: accumulated_tweak + tweak,
+            negated: self.negated ^ negate,
+        })
+    }
+
+    /// Applies the BIP-341 Taproot tweak committing to an optional script tree merkle root.
+    pub fn apply_taproot_tweak(
+        &self,
+        merkle_root: Option<[u8; 32]>,
+    ) -> Result<TweakedGroupKey, NoistError> {
+        // Taproot tweak (t) is = H_TapTweak(bytes(Q)||root).
+        let mut tweak_preimage = Vec::<u8>::with_capacity(64);
+        tweak_preimage.extend(self.output_key.serialize_xonly());
+
+        if let Some(merkle_root) = merkle_root {
+            tweak_preimage.extend(merkle_root);
+        }
+
+        let tweak = tagged_hash(tweak_preimage, HashTag::TapTweak).into_scalar()?;
+
+        self.apply_tweak(tweak, TweakMode::XOnly)
+    }
+
+    /// Secret share adjusted by the accumulated sign (g), to be used for signing under the output key.
+    pub fn tweak_secret_share(&self, secret_share: &SecretShare) -> SecretShare {
+        match self.negated {
+            true => SecretShare::new(secret_share.index, -secret_share.secret.expose()),
+            false => secret_share.clone(),
+        }
+    }
+
+    /// Public share adjusted by the accumulated sign (g), to be used for verifying under the output key.
+    pub fn tweak_public_share(&self, public_share: &PublicShare) -> PublicShare {
+        match self.negated {
+            true => PublicShare::new(public_share.index, -public_share.point),
+            false => *public_share,
+        }
+    }
+}
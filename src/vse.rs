@@ -1,39 +1,53 @@
-use crate::{hash::sha_256, into::SecpError};
+use crate::{
+    error::NoistError,
+    hash::{sha_256, tagged_hash, HashTag},
+    into::IntoScalar,
+    sensitive::SecretScalar,
+};
+use rand::{CryptoRng, RngCore};
 use secp::{MaybePoint, MaybeScalar, Point, Scalar};
+use zeroize::Zeroizing;
 
-pub fn vse_encrypting_key_secret(self_secret: Scalar, to_public: Point) -> Scalar {
+pub fn vse_encrypting_key_secret(self_secret: Scalar, to_public: Point) -> SecretScalar {
     let secret_point = self_secret * to_public;
 
-    let secret_point_xbytes = secret_point.serialize_uncompressed();
+    vse_encrypting_key_from_point(secret_point)
+}
 
-    let secret_point_hash = sha_256(&secret_point_xbytes);
+pub(crate) fn vse_encrypting_key_from_point(secret_point: Point) -> SecretScalar {
+    // Shared point bytes and their hash are wiped once the key is derived.
+    let secret_point_xbytes = Zeroizing::new(secret_point.serialize_uncompressed());
 
-    let shared_secret = Scalar::reduce_from(&secret_point_hash);
+    let secret_point_hash = Zeroizing::new(sha_256(*secret_point_xbytes));
 
-    shared_secret
+    SecretScalar::new(Scalar::reduce_from(&secret_point_hash))
 }
 
 pub fn vse_encrypting_key_public(self_secret: Scalar, to_public: Point) -> Point {
-    vse_encrypting_key_secret(self_secret, to_public).base_point_mul()
+    vse_encrypting_key_secret(self_secret, to_public)
+        .expose()
+        .base_point_mul()
 }
 
+/// Publicly verifiable masking of a share, checked with `vse_verify`.
+/// Confidential share delivery should use `transport` instead.
 pub fn vse_encrypt(
     secret_to_encrypt: Scalar,
     encrypting_key_secret: Scalar,
-) -> Result<Scalar, SecpError> {
+) -> Result<Scalar, NoistError> {
     match secret_to_encrypt + encrypting_key_secret {
         MaybeScalar::Valid(scalar) => Ok(scalar),
-        MaybeScalar::Zero => Err(SecpError::InvalidScalar),
+        MaybeScalar::Zero => Err(NoistError::InvalidScalar),
     }
 }
 
 pub fn vse_decrypt(
     secret_to_decrypt: Scalar,
     encrypting_key_secret: Scalar,
-) -> Result<Scalar, SecpError> {
+) -> Result<Scalar, NoistError> {
     match secret_to_decrypt - encrypting_key_secret {
         MaybeScalar::Valid(scalar) => Ok(scalar),
-        MaybeScalar::Zero => Err(SecpError::InvalidScalar),
+        MaybeScalar::Zero => Err(NoistError::DecryptionFailure),
     }
 }
 
@@ -50,3 +64,164 @@ pub fn vse_verify(
             MaybePoint::Infinity => return false,
         }
 }
+
+/// Chaum-Pedersen proof (c, z) that log_G1(P1) equals log_G2(P2).
+///
+/// Used by complaints to prove a revealed ECDH point was computed with the accuser's key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DleqProof {
+    pub challenge: Scalar,
+    pub response: Scalar,
+}
+
+fn dleq_challenge(
+    base_1: Point,
+    point_1: Point,
+    base_2: Point,
+    point_2: Point,
+    nonce_1: Point,
+    nonce_2: Point,
+) -> Result<Scalar, NoistError> {
+    let mut challenge_preimage = Vec::<u8>::with_capacity(198);
+    challenge_preimage.extend(base_1.serialize());
+    challenge_preimage.extend(point_1.serialize());
+    challenge_preimage.extend(base_2.serialize());
+    challenge_preimage.extend(point_2.serialize());
+    challenge_preimage.extend(nonce_1.serialize());
+    challenge_preimage.extend(nonce_2.serialize());
+
+    tagged_hash(challenge_preimage, HashTag::VSEProof).into_scalar()
+}
+
+pub fn dleq_prove(secret: Scalar, base_1: Point, base_2: Point) -> Result<DleqProof, NoistError> {
+    dleq_prove_with_rng(&mut rand::thread_rng(), secret, base_1, base_2)
+}
+
+/// Same as `dleq_prove`, drawing the proof nonce from the given RNG.
+pub fn dleq_prove_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret: Scalar,
+    base_1: Point,
+    base_2: Point,
+) -> Result<DleqProof, NoistError> {
+    // Nonces (A_1, A_2) are = kG_1 and kG_2.
+    let secret_nonce = SecretScalar::random_with_rng(rng)?;
+    let nonce_1 = secret_nonce.expose() * base_1;
+    let nonce_2 = secret_nonce.expose() * base_2;
+
+    // Challenge (c) is = H(G_1||P_1||G_2||P_2||A_1||A_2).
+    let challenge = dleq_challenge(
+        base_1,
+        secret * base_1,
+        base_2,
+        secret * base_2,
+        nonce_1,
+        nonce_2,
+    )?;
+
+    // Response (z) is = k + cx mod n.
+    let response = match secret_nonce.expose() + challenge * secret {
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => scalar,
+    };
+
+    Ok(DleqProof {
+        challenge,
+        response,
+    })
+}
+
+pub fn dleq_verify(
+    base_1: Point,
+    point_1: Point,
+    base_2: Point,
+    point_2: Point,
+    proof: &DleqProof,
+) -> bool {
+    // Nonces (A_1, A_2) are = zG_1 - cP_1 and zG_2 - cP_2.
+    let nonce_1 = match proof.response * base_1 - proof.challenge * point_1 {
+        MaybePoint::Infinity => return false,
+        MaybePoint::Valid(point) => point,
+    };
+
+    let nonce_2 = match proof.response * base_2 - proof.challenge * point_2 {
+        MaybePoint::Infinity => return false,
+        MaybePoint::Valid(point) => point,
+    };
+
+    match dleq_challenge(base_1, point_1, base_2, point_2, nonce_1, nonce_2) {
+        Ok(challenge) => challenge == proof.challenge,
+        Err(_) => false,
+    }
+}
+
+/// Share encrypted to one recipient under a fresh ephemeral key.
+///
+/// The pad (k) is = H(rPK_to), so only the recipient can derive it, while the pad
+/// commitment (K) is = kG lets anyone check the ciphertext against the VSS commitments.
+///
+/// Nothing proves that K was derived from the recipient's key: a hashed pad cannot be covered
+/// by a DLEQ proof. Third parties can check consistency with `vse_verify_share`, but whether
+/// the recipient can decrypt is only settled when they complain, see `complaint_resolve`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncryptedShare {
+    pub ephemeral_key: Point,
+    pub pad_commitment: Point,
+    pub encrypted_share: Scalar,
+}
+
+pub fn vse_encrypt_share(
+    share_secret: Scalar,
+    to_public: Point,
+) -> Result<EncryptedShare, NoistError> {
+    vse_encrypt_share_with_rng(&mut rand::thread_rng(), share_secret, to_public)
+}
+
+/// Same as `vse_encrypt_share`, drawing the ephemeral key from the given RNG.
+pub fn vse_encrypt_share_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    share_secret: Scalar,
+    to_public: Point,
+) -> Result<EncryptedShare, NoistError> {
+    // Ephemeral key (R) is = rG, so revealing one pad reveals nothing about other shares.
+    let ephemeral_secret = SecretScalar::random_with_rng(rng)?;
+    let ephemeral_key = ephemeral_secret.expose().base_point_mul();
+
+    // Pad (k) is = H(rPK_to).
+    let pad = vse_encrypting_key_secret(ephemeral_secret.expose(), to_public);
+
+    // Encrypted share (enc) is = s_i + k.
+    let encrypted_share = vse_encrypt(share_secret, pad.expose())?;
+
+    Ok(EncryptedShare {
+        ephemeral_key,
+        pad_commitment: pad.expose().base_point_mul(),
+        encrypted_share,
+    })
+}
+
+/// Checks the encrypted share against the public share (S_i) without learning the pad.
+///
+/// This does not prove the share is decryptable by its recipient, since the dealer picks K.
+pub fn vse_verify_share(encrypted: &EncryptedShare, public_share_point: Point) -> bool {
+    // Encrypted share must satisfy encG = S_i + K.
+    vse_verify(
+        encrypted.encrypted_share,
+        public_share_point,
+        encrypted.pad_commitment,
+    )
+}
+
+pub fn vse_decrypt_share(
+    encrypted: &EncryptedShare,
+    self_secret: Scalar,
+) -> Result<Scalar, NoistError> {
+    // Pad (k) is = H(sk_to * R), and must match the published pad commitment.
+    let pad = vse_encrypting_key_secret(self_secret, encrypted.ephemeral_key);
+
+    if pad.expose().base_point_mul() != encrypted.pad_commitment {
+        return Err(NoistError::DecryptionFailure);
+    }
+
+    vse_decrypt(encrypted.encrypted_share, pad.expose())
+}
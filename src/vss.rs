@@ -1,9 +1,45 @@
-use secp::{MaybePoint, Point, Scalar};
+use k256::elliptic_curve::ops::LinearCombinationExt;
+use rand::{CryptoRng, RngCore};
+use secp::{MaybePoint, MaybeScalar, Point, Scalar};
 
-use crate::into::SecpError;
+use crate::{
+    error::NoistError,
+    hash::HashTag,
+    schnorr::compute_proof_challenge,
+    secret::random_scalar_with_rng,
+    sensitive::SecretScalar,
+    share::{ParticipantIndex, PublicShare, SecretShare},
+};
+
+/// Feldman commitments (A_0, ..., A_t-1) to the coefficients of a sharing polynomial.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VssCommitment(Vec<Point>);
+
+impl VssCommitment {
+    pub fn new(points: Vec<Point>) -> Result<VssCommitment, NoistError> {
+        match points.is_empty() {
+            true => Err(NoistError::InvalidPoint),
+            false => Ok(VssCommitment(points)),
+        }
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.0
+    }
+
+    /// Commitment to the constant term (A_0), which is the shared public key.
+    pub fn constant(&self) -> Point {
+        self.0[0]
+    }
+
+    /// Number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.0.len()
+    }
+}
 
 #[allow(non_snake_case)]
-pub fn vss_commit(coeffs: &Vec<Scalar>) -> Result<Vec<Point>, SecpError> {
+pub fn vss_commit(coeffs: &[Scalar]) -> Result<VssCommitment, NoistError> {
     let mut vss_commitments = Vec::<Point>::new();
 
     for coeff in coeffs {
@@ -11,51 +47,349 @@ pub fn vss_commit(coeffs: &Vec<Scalar>) -> Result<Vec<Point>, SecpError> {
         vss_commitments.push(A_i);
     }
 
-    Ok(vss_commitments)
+    VssCommitment::new(vss_commitments)
 }
 
-#[allow(non_snake_case)]
-pub fn vss_verify_point(share_i: (Scalar, Point), vss_commitments: &Vec<Point>) -> bool {
-    let (i, P_i) = share_i;
+/// Schnorr proof (R, μ) that the dealer knows the constant term a_0 behind A_0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VssProof {
+    pub public_nonce: Point,
+    pub response: Scalar,
+}
+
+fn vss_proof_message(index: ParticipantIndex, context: &[u8]) -> Vec<u8> {
+    // Message is = i||len(ctx)||ctx, binding the proof to the dealer and the session.
+    let mut message = Vec::<u8>::with_capacity(8 + context.len());
+    message.extend(index.value().to_be_bytes());
+    message.extend((context.len() as u32).to_be_bytes());
+    message.extend(context);
+
+    message
+}
+
+/// Proves knowledge of the constant term (a_0) committed to by `vss_commit`, so that a dealer
+/// cannot publish a commitment derived from others' (rogue-key attack).
+pub fn vss_prove(
+    secret: Scalar,
+    index: ParticipantIndex,
+    context: &[u8],
+) -> Result<VssProof, NoistError> {
+    vss_prove_with_rng(&mut rand::thread_rng(), secret, index, context)
+}
+
+/// Same as `vss_prove`, drawing the proof nonce from the given RNG.
+pub fn vss_prove_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret: Scalar,
+    index: ParticipantIndex,
+    context: &[u8],
+) -> Result<VssProof, NoistError> {
+    // Nonce (R) is = kG.
+    let secret_nonce = SecretScalar::new(random_scalar_with_rng(rng)?);
+    let public_nonce = secret_nonce.expose().base_point_mul();
+
+    // Challenge (c) is = H(R||A_0||i||ctx).
+    let challenge = compute_proof_challenge(
+        public_nonce,
+        secret.base_point_mul(),
+        &vss_proof_message(index, context),
+        HashTag::VSSProofOfKnowledge,
+    )?;
+
+    // Response (μ) is = k + c * a_0 mod n.
+    let response = match secret_nonce.expose() + challenge * secret {
+        MaybeScalar::Zero => return Err(NoistError::InvalidScalar),
+        MaybeScalar::Valid(scalar) => scalar,
+    };
+
+    Ok(VssProof {
+        public_nonce,
+        response,
+    })
+}
+
+pub fn vss_verify_proof(
+    vss_commitments: &VssCommitment,
+    index: ParticipantIndex,
+    context: &[u8],
+    proof: &VssProof,
+) -> bool {
+    let constant_commitment = vss_commitments.constant();
+
+    let challenge = match compute_proof_challenge(
+        proof.public_nonce,
+        constant_commitment,
+        &vss_proof_message(index, context),
+        HashTag::VSSProofOfKnowledge,
+    ) {
+        Ok(scalar) => scalar,
+        Err(_) => return false,
+    };
+
+    // Check if μG equals to R + cA_0.
+    proof.response.base_point_mul()
+        == match proof.public_nonce + challenge * constant_commitment {
+            MaybePoint::Infinity => return false,
+            MaybePoint::Valid(point) => point,
+        }
+}
 
-    let mut P_i_computed = MaybePoint::Infinity;
+pub fn vss_public_share(
+    index: ParticipantIndex,
+    vss_commitments: &VssCommitment,
+) -> Result<PublicShare, NoistError> {
+    // Public share (P_i) is = sum(A_j * i^j), evaluated as a single multi-scalar multiplication.
+    let powers = scalar_powers(index.to_scalar(), vss_commitments.threshold());
 
-    for j in 0..vss_commitments.len() as u32 {
-        P_i_computed += vss_commitments[j as usize] * pow_scalar(i, j);
+    let terms: Vec<(Point, MaybeScalar)> = vss_commitments
+        .points()
+        .iter()
+        .copied()
+        .zip(powers.into_iter().map(MaybeScalar::Valid))
+        .collect();
+
+    match multi_scalar_mul(&terms) {
+        MaybePoint::Infinity => Err(NoistError::InvalidPoint),
+        MaybePoint::Valid(point) => Ok(PublicShare::new(index, point)),
     }
+}
 
-    P_i == match P_i_computed {
-        MaybePoint::Infinity => return false,
-        MaybePoint::Valid(point) => point,
+#[allow(non_snake_case)]
+pub fn vss_verify_point(share_i: &PublicShare, vss_commitments: &VssCommitment) -> bool {
+    let P_i = share_i.point;
+
+    match vss_public_share(share_i.index, vss_commitments) {
+        Ok(P_i_computed) => P_i == P_i_computed.point,
+        Err(_) => false,
     }
 }
 
 #[allow(non_snake_case)]
-pub fn vss_verify_secret(share_i: (Scalar, Scalar), vss_commitments: &Vec<Point>) -> bool {
-    let (i, sk_i) = share_i;
-    let S_i = sk_i.base_point_mul();
+pub fn vss_verify_secret(share_i: &SecretShare, vss_commitments: &VssCommitment) -> bool {
+    let S_i = share_i.secret.expose().base_point_mul();
+
+    match vss_public_share(share_i.index, vss_commitments) {
+        Ok(S_i_computed) => S_i == S_i_computed.point,
+        Err(_) => false,
+    }
+}
+
+/// Verifies many public shares at once against the same commitments.
+///
+/// Checks sum(r_i * P_i) == sum(A_j * sum(r_i * i^j)) for random weights r_i, falling back to
+/// verifying one by one to report the first offending share.
+pub fn vss_verify_points(
+    shares: &[PublicShare],
+    vss_commitments: &VssCommitment,
+) -> Result<(), NoistError> {
+    vss_verify_points_with_rng(&mut rand::thread_rng(), shares, vss_commitments)
+}
+
+/// Same as `vss_verify_points`, drawing the batch weights from the given RNG.
+pub fn vss_verify_points_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    shares: &[PublicShare],
+    vss_commitments: &VssCommitment,
+) -> Result<(), NoistError> {
+    let weights = batch_weights(rng, shares.len())?;
+
+    let mut terms = Vec::<(Point, MaybeScalar)>::with_capacity(shares.len());
+
+    for (share, weight) in shares.iter().zip(weights.iter()) {
+        terms.push((share.point, MaybeScalar::Valid(*weight)));
+    }
+
+    let lhs = multi_scalar_mul(&terms);
+
+    match lhs
+        == vss_batch_rhs(
+            shares.iter().map(|share| share.index),
+            &weights,
+            vss_commitments,
+        ) {
+        true => Ok(()),
+        false => Err(vss_batch_blame(shares.iter().map(|share| {
+            (share.index, vss_verify_point(share, vss_commitments))
+        }))),
+    }
+}
+
+/// Same as `vss_verify_points`, for secret shares.
+pub fn vss_verify_secrets(
+    shares: &[SecretShare],
+    vss_commitments: &VssCommitment,
+) -> Result<(), NoistError> {
+    vss_verify_secrets_with_rng(&mut rand::thread_rng(), shares, vss_commitments)
+}
+
+/// Same as `vss_verify_secrets`, drawing the batch weights from the given RNG.
+pub fn vss_verify_secrets_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    shares: &[SecretShare],
+    vss_commitments: &VssCommitment,
+) -> Result<(), NoistError> {
+    let weights = batch_weights(rng, shares.len())?;
+
+    // Left-hand side is = (sum(r_i * s_i))G.
+    let mut lhs = MaybeScalar::Zero;
+
+    for (share, weight) in shares.iter().zip(weights.iter()) {
+        lhs += share.secret.expose() * *weight;
+    }
+
+    match lhs.base_point_mul()
+        == vss_batch_rhs(
+            shares.iter().map(|share| share.index),
+            &weights,
+            vss_commitments,
+        ) {
+        true => Ok(()),
+        false => Err(vss_batch_blame(shares.iter().map(|share| {
+            (share.index, vss_verify_secret(share, vss_commitments))
+        }))),
+    }
+}
+
+fn batch_weights<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    count: usize,
+) -> Result<Vec<Scalar>, NoistError> {
+    // First weight is one, the rest are random so that invalid shares cannot cancel out.
+    let mut weights = Vec::<Scalar>::with_capacity(count);
+
+    for position in 0..count {
+        weights.push(match position {
+            0 => Scalar::one(),
+            _ => random_scalar_with_rng(rng)?,
+        });
+    }
+
+    Ok(weights)
+}
+
+fn vss_batch_rhs(
+    indexes: impl Iterator<Item = ParticipantIndex>,
+    weights: &[Scalar],
+    vss_commitments: &VssCommitment,
+) -> MaybePoint {
+    // Coefficient (c_j) of A_j is = sum(r_i * i^j).
+    let mut coefficients = vec![MaybeScalar::Zero; vss_commitments.threshold()];
+
+    for (index, weight) in indexes.zip(weights.iter()) {
+        let i = index.to_scalar();
+        let mut power = *weight;
+
+        for coefficient in coefficients.iter_mut() {
+            *coefficient += power;
+            power *= i;
+        }
+    }
+
+    let terms: Vec<(Point, MaybeScalar)> = vss_commitments
+        .points()
+        .iter()
+        .copied()
+        .zip(coefficients)
+        .collect();
+
+    multi_scalar_mul(&terms)
+}
 
-    let mut S_i_computed = MaybePoint::Infinity;
+fn vss_batch_blame(results: impl Iterator<Item = (ParticipantIndex, bool)>) -> NoistError {
+    for (index, valid) in results {
+        if !valid {
+            return NoistError::VssMismatch(index);
+        }
+    }
+
+    // Random weights made an all-valid batch fail, which is negligibly unlikely.
+    NoistError::InvalidPoint
+}
 
-    for j in 0..vss_commitments.len() as u32 {
-        S_i_computed += vss_commitments[j as usize] * pow_scalar(i, j);
+/// Powers (1, x, x^2, ..., x^(count-1)), each computed from the previous one.
+pub fn scalar_powers(base: Scalar, count: usize) -> Vec<Scalar> {
+    let mut powers = Vec::<Scalar>::with_capacity(count);
+    let mut power = Scalar::one();
+
+    for _ in 0..count {
+        powers.push(power);
+        power *= base;
     }
 
-    S_i == match S_i_computed {
-        MaybePoint::Infinity => return false,
-        MaybePoint::Valid(point) => point,
+    powers
+}
+
+/// Computes sum(s_j * P_j) with a single interleaved multi-scalar multiplication.
+pub fn multi_scalar_mul(terms: &[(Point, MaybeScalar)]) -> MaybePoint {
+    let terms: Vec<(k256::ProjectivePoint, k256::Scalar)> = terms
+        .iter()
+        .filter_map(|(point, scalar)| match scalar {
+            MaybeScalar::Zero => None,
+            MaybeScalar::Valid(scalar) => Some((
+                k256::ProjectivePoint::from(k256::AffinePoint::from(*point)),
+                *k256::NonZeroScalar::from(*scalar),
+            )),
+        })
+        .collect();
+
+    point_from_projective(k256::ProjectivePoint::lincomb_ext(&terms[..]))
+}
+
+pub(crate) fn point_from_projective(point: k256::ProjectivePoint) -> MaybePoint {
+    // Converted through a public key, which rejects the identity point.
+    match k256::PublicKey::from_affine(point.to_affine()) {
+        Ok(public_key) => MaybePoint::Valid(Point::from(public_key)),
+        Err(_) => MaybePoint::Infinity,
     }
 }
 
 pub fn pow_scalar(base: Scalar, power: u32) -> Scalar {
-    let mut result = match power {
-        0 => return Scalar::one(),
-        _ => base,
-    };
+    // Square-and-multiply over the bits of the exponent.
+    let mut result = Scalar::one();
+    let mut square = base;
+    let mut power = power;
+
+    while power > 0 {
+        if power & 1 == 1 {
+            result *= square;
+        }
 
-    for _ in 0..(power - 1) {
-        result = result * base;
+        square *= square;
+        power >>= 1;
     }
 
     result
 }
+
+pub fn vss_combine(vss_commitments_vec: &[&VssCommitment]) -> Result<VssCommitment, NoistError> {
+    let len = match vss_commitments_vec.first() {
+        Some(vss_commitments) => vss_commitments.threshold(),
+        None => return Err(NoistError::InvalidPoint),
+    };
+
+    let mut combined = vec![MaybePoint::Infinity; len];
+
+    for vss_commitments in vss_commitments_vec {
+        if vss_commitments.threshold() != len {
+            return Err(NoistError::ThresholdMismatch {
+                expected: len,
+                found: vss_commitments.threshold(),
+            });
+        }
+
+        for (sum, point) in combined.iter_mut().zip(vss_commitments.points().iter()) {
+            *sum += *point;
+        }
+    }
+
+    let mut vss_commitments = Vec::<Point>::with_capacity(len);
+
+    for point in combined {
+        vss_commitments.push(match point {
+            MaybePoint::Infinity => return Err(NoistError::InvalidPoint),
+            MaybePoint::Valid(point) => point,
+        });
+    }
+
+    VssCommitment::new(vss_commitments)
+}
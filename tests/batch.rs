@@ -0,0 +1,94 @@
+mod batch_tests {
+    use noist::{
+        error::NoistError,
+        schnorr::{
+            sign_schnorr_message, verify_schnorr_batch, verify_schnorr_batch_with_rng,
+            SchnorrBatchItem, SignFlag,
+        },
+        secret::deterministic_rng,
+    };
+    use secp::Scalar;
+
+    type SignedMessage = ([u8; 32], Vec<u8>, [u8; 64]);
+
+    fn signed_messages(count: usize) -> Result<Vec<SignedMessage>, NoistError> {
+        let mut signed = Vec::<SignedMessage>::new();
+
+        for position in 0..count {
+            let secret_key = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+            // Messages of varying length, including an empty one.
+            let message: Vec<u8> = (0..position * 7).map(|_| rand::random::<u8>()).collect();
+
+            let signature =
+                sign_schnorr_message(secret_key.serialize(), &message, SignFlag::BIP0340Sign)?;
+
+            signed.push((
+                secret_key.base_point_mul().serialize_xonly(),
+                message,
+                signature,
+            ));
+        }
+
+        Ok(signed)
+    }
+
+    fn batch_items(signed: &[SignedMessage]) -> Vec<SchnorrBatchItem<'_>> {
+        signed
+            .iter()
+            .map(|(public_key, message, signature)| (*public_key, &message[..], *signature))
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_verify() -> Result<(), NoistError> {
+        let signed = signed_messages(16)?;
+
+        verify_schnorr_batch(&batch_items(&signed), SignFlag::BIP0340Sign)?;
+        verify_schnorr_batch(&[], SignFlag::BIP0340Sign)?;
+
+        // Caller-provided RNG for the batch weights.
+        verify_schnorr_batch_with_rng(
+            &mut deterministic_rng([7; 32]),
+            &batch_items(&signed),
+            SignFlag::BIP0340Sign,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_verify_invalid() -> Result<(), NoistError> {
+        let mut signed = signed_messages(8)?;
+
+        // Signature 2 is over a different message.
+        signed[2].1[0] ^= 0x01;
+
+        // Signature 5 has an unparsable commitment.
+        signed[5].2[32..].copy_from_slice(&[0xff; 32]);
+
+        assert_eq!(
+            verify_schnorr_batch(&batch_items(&signed), SignFlag::BIP0340Sign),
+            Err(NoistError::InvalidSignatures(vec![2, 5]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_verify_swapped() -> Result<(), NoistError> {
+        let mut signed = signed_messages(4)?;
+
+        // Swapping signatures between entries must not pass.
+        let signature = signed[0].2;
+        signed[0].2 = signed[1].2;
+        signed[1].2 = signature;
+
+        assert_eq!(
+            verify_schnorr_batch(&batch_items(&signed), SignFlag::BIP0340Sign),
+            Err(NoistError::InvalidSignatures(vec![0, 1]))
+        );
+
+        Ok(())
+    }
+}
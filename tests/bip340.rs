@@ -0,0 +1,88 @@
+mod bip340_tests {
+    use noist::{
+        error::NoistError,
+        schnorr::{sign_schnorr_message_aux, verify_schnorr_message, SignFlag},
+    };
+
+    const TEST_VECTORS: &str = include_str!("data/bip340-test-vectors.csv");
+
+    struct TestVector {
+        index: String,
+        secret_key: Option<[u8; 32]>,
+        public_key: [u8; 32],
+        aux_rand: Option<[u8; 32]>,
+        message: Vec<u8>,
+        signature: [u8; 64],
+        verification_result: bool,
+    }
+
+    fn parse_array<const N: usize>(field: &str) -> Option<[u8; N]> {
+        hex::decode(field).ok()?.try_into().ok()
+    }
+
+    fn test_vectors() -> Vec<TestVector> {
+        TEST_VECTORS
+            .lines()
+            .skip(1)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let fields: Vec<&str> = line.splitn(8, ',').collect();
+
+                TestVector {
+                    index: fields[0].to_string(),
+                    secret_key: parse_array(fields[1]),
+                    // Public keys that are not valid points still decode as 32 bytes.
+                    public_key: parse_array(fields[2]).unwrap(),
+                    aux_rand: parse_array(fields[3]),
+                    message: hex::decode(fields[4]).unwrap(),
+                    signature: parse_array(fields[5]).unwrap(),
+                    verification_result: fields[6] == "TRUE",
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bip340_sign_vectors() -> Result<(), NoistError> {
+        for vector in test_vectors() {
+            let (secret_key, aux_rand) = match (vector.secret_key, vector.aux_rand) {
+                (Some(secret_key), Some(aux_rand)) => (secret_key, aux_rand),
+                _ => continue,
+            };
+
+            let signature = sign_schnorr_message_aux(
+                secret_key,
+                &vector.message,
+                aux_rand,
+                SignFlag::BIP0340AuxSign,
+            )?;
+
+            assert_eq!(
+                signature, vector.signature,
+                "wrong signature for index {}",
+                vector.index
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bip340_verify_vectors() {
+        for vector in test_vectors() {
+            let result = verify_schnorr_message(
+                vector.public_key,
+                &vector.message,
+                vector.signature,
+                SignFlag::BIP0340AuxSign,
+            );
+
+            assert_eq!(
+                result.is_ok(),
+                vector.verification_result,
+                "wrong verification result for index {}",
+                vector.index
+            );
+        }
+    }
+}
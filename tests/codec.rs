@@ -0,0 +1,301 @@
+mod common;
+
+mod codec_tests {
+    use std::fmt::Debug;
+
+    use noist::{
+        codec::{Codec, CODEC_VERSION},
+        complaint::complaint_create,
+        dkg::{dkg_deal, KeyPackage},
+        error::NoistError,
+        nonce::{nonce_deal, NonceShare},
+        pedersen::pedersen_share_gen,
+        refresh::refresh_deal,
+        repair::{repair_deal, RepairContribution},
+        reshare::reshare_deal,
+        schnorr::SignFlag,
+        secret::{random_scalar, secret_share_gen},
+        session::partial_sign,
+        share::{ParticipantIndex, PublicShare, SecretShare},
+        transport::transport_encrypt_share,
+        vse::{dleq_prove, vse_encrypt_share},
+        vss::{vss_prove, VssCommitment},
+    };
+    use secp::Point;
+
+    use crate::common::nonce_slot;
+
+    fn round_trip<T: Codec + PartialEq + Debug>(item: &T) -> Result<(), NoistError> {
+        let bytes = item.to_bytes();
+
+        assert_eq!(bytes[0], CODEC_VERSION);
+        assert_eq!(bytes[1], T::TAG);
+        assert_eq!(&T::from_bytes(&bytes)?, item);
+
+        // Every truncation is rejected.
+        for len in 0..bytes.len() {
+            assert!(T::from_bytes(&bytes[..len]).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_codec_round_trip() -> Result<(), NoistError> {
+        let index = ParticipantIndex::new(7)?;
+        let (secret_shares, group_key, vss_commitments) = secret_share_gen(random_scalar()?, 5, 3)?;
+        let (nonces, slot_nonce) = nonce_slot(5, 3)?;
+
+        round_trip(&index)?;
+        round_trip(&random_scalar()?)?;
+        round_trip(&group_key)?;
+        round_trip(&secret_shares[0])?;
+        round_trip(&secret_shares[0].public_share())?;
+        round_trip(&vss_commitments)?;
+
+        round_trip(&partial_sign(
+            group_key,
+            &secret_shares[0],
+            &nonces[0],
+            &[0xab; 32],
+            SignFlag::BIP0340Sign,
+        )?)?;
+        round_trip(&slot_nonce)?;
+
+        round_trip(&KeyPackage::new(
+            secret_shares[0].clone(),
+            vss_commitments.clone(),
+        ))?;
+
+        let sender_secret = random_scalar()?;
+        let recipient_secret = random_scalar()?;
+
+        round_trip(&transport_encrypt_share(
+            sender_secret,
+            recipient_secret.base_point_mul(),
+            index,
+            rand::random::<[u8; 32]>(),
+            &secret_shares[1],
+        )?)?;
+
+        round_trip(&dleq_prove(
+            sender_secret,
+            Point::generator(),
+            recipient_secret.base_point_mul(),
+        )?)?;
+
+        let encrypted_share = vse_encrypt_share(
+            secret_shares[1].secret.expose(),
+            recipient_secret.base_point_mul(),
+        )?;
+
+        round_trip(&encrypted_share)?;
+
+        round_trip(&complaint_create(
+            secret_shares[1].index,
+            index,
+            recipient_secret,
+            &encrypted_share,
+        )?)?;
+
+        let helpers: Vec<ParticipantIndex> =
+            secret_shares[..3].iter().map(|share| share.index).collect();
+
+        round_trip(&repair_deal(&secret_shares[0], &helpers, secret_shares[4].index)?[0])?;
+
+        round_trip(&vss_prove(sender_secret, index, b"context")?)?;
+
+        let (pedersen_shares, pedersen_commitments) = pedersen_share_gen(random_scalar()?, 5, 3)?;
+
+        round_trip(&pedersen_shares[0])?;
+        round_trip(&pedersen_commitments)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_codec_round_trip_dealings() -> Result<(), NoistError> {
+        let index = ParticipantIndex::new(2)?;
+
+        round_trip(&dkg_deal(index, 5, 3, b"context")?.0)?;
+
+        let (nonce_dealing, nonce_shares) = nonce_deal(index, 10, 4, 5, 3)?;
+
+        round_trip(&nonce_dealing)?;
+        round_trip(&nonce_dealing.vss_commitments[0])?;
+        round_trip(&nonce_shares[0][0])?;
+
+        round_trip(&refresh_deal(index, 5, 3)?.0)?;
+
+        let (secret_shares, _, _) = secret_share_gen(random_scalar()?, 3, 2)?;
+        let dealers = [secret_shares[0].index, secret_shares[1].index];
+
+        round_trip(&reshare_deal(&secret_shares[0], &dealers, 4, 3)?.0)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_codec_round_trip_batches() -> Result<(), NoistError> {
+        let index = ParticipantIndex::new(2)?;
+
+        // Nonce shares a dealer sends to one participant.
+        let (_, nonce_shares) = nonce_deal(index, 0, 4, 5, 3)?;
+
+        round_trip(&nonce_shares[0])?;
+        round_trip(&Vec::<NonceShare>::new())?;
+
+        // Repair contributions a helper sends out.
+        let (secret_shares, _, _) = secret_share_gen(random_scalar()?, 5, 3)?;
+        let helpers: Vec<ParticipantIndex> =
+            secret_shares[..3].iter().map(|share| share.index).collect();
+
+        let contributions = repair_deal(&secret_shares[0], &helpers, secret_shares[4].index)?;
+
+        round_trip(&contributions)?;
+
+        // Batch tag is bound to the item type.
+        let bytes = contributions.to_bytes();
+        assert_eq!(bytes[1], 0x80 | RepairContribution::TAG);
+        assert!(Vec::<NonceShare>::from_bytes(&bytes).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_codec_reject_malformed() -> Result<(), NoistError> {
+        let (secret_shares, _, vss_commitments) = secret_share_gen(random_scalar()?, 3, 2)?;
+
+        let mut bytes = secret_shares[0].to_bytes();
+
+        // Unknown version.
+        bytes[0] = 2;
+        assert_eq!(
+            SecretShare::from_bytes(&bytes),
+            Err(NoistError::UnsupportedVersion(2))
+        );
+
+        // Wrong type tag.
+        let bytes = secret_shares[0].public_share().to_bytes();
+        assert_eq!(
+            SecretShare::from_bytes(&bytes),
+            Err(NoistError::ParseError { offset: 1 })
+        );
+
+        // Trailing bytes.
+        let mut bytes = secret_shares[0].to_bytes();
+        bytes.push(0);
+        assert_eq!(
+            SecretShare::from_bytes(&bytes),
+            Err(NoistError::ParseError { offset: 38 })
+        );
+
+        // Zero participant index.
+        let mut bytes = secret_shares[0].to_bytes();
+        bytes[2..6].copy_from_slice(&[0; 4]);
+        assert_eq!(SecretShare::from_bytes(&bytes), Err(NoistError::ZeroIndex));
+
+        // Scalar equal to the curve order.
+        let mut bytes = secret_shares[0].to_bytes();
+        bytes[6..38].copy_from_slice(
+            &hex::decode("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141")
+                .unwrap(),
+        );
+        assert_eq!(
+            SecretShare::from_bytes(&bytes),
+            Err(NoistError::ParseError { offset: 6 })
+        );
+
+        // Point with an invalid prefix.
+        let mut bytes = vss_commitments.to_bytes();
+        bytes[6] = 0x05;
+        assert_eq!(
+            VssCommitment::from_bytes(&bytes),
+            Err(NoistError::ParseError { offset: 6 })
+        );
+
+        // Empty commitment vector.
+        let bytes = vec![CODEC_VERSION, VssCommitment::TAG, 0, 0, 0, 0];
+        assert_eq!(
+            VssCommitment::from_bytes(&bytes),
+            Err(NoistError::InvalidPoint)
+        );
+
+        // Count prefix larger than the input.
+        let bytes = vec![CODEC_VERSION, VssCommitment::TAG, 0xff, 0xff, 0xff, 0xff];
+        assert!(VssCommitment::from_bytes(&bytes).is_err());
+
+        // Key package with inconsistent fields.
+        let key_package = KeyPackage::new(secret_shares[0].clone(), vss_commitments.clone());
+
+        let malformed = KeyPackage {
+            public_share: secret_shares[1].public_share(),
+            ..key_package.clone()
+        };
+        assert_eq!(
+            KeyPackage::from_bytes(&malformed.to_bytes()),
+            Err(NoistError::IndexMismatch {
+                expected: secret_shares[0].index,
+                found: secret_shares[1].index
+            })
+        );
+
+        let malformed = KeyPackage {
+            public_share: PublicShare {
+                index: secret_shares[0].index,
+                point: secret_shares[1].public_share().point,
+            },
+            ..key_package.clone()
+        };
+        assert_eq!(
+            KeyPackage::from_bytes(&malformed.to_bytes()),
+            Err(NoistError::VssMismatch(secret_shares[0].index))
+        );
+
+        let malformed = KeyPackage {
+            group_key: random_scalar()?.base_point_mul(),
+            ..key_package.clone()
+        };
+        assert_eq!(
+            KeyPackage::from_bytes(&malformed.to_bytes()),
+            Err(NoistError::VssMismatch(secret_shares[0].index))
+        );
+
+        // Share that does not lie on the commitments.
+        let secret_share = SecretShare::new(secret_shares[0].index, random_scalar()?);
+        let malformed = KeyPackage {
+            public_share: secret_share.public_share(),
+            secret_share,
+            ..key_package
+        };
+        assert_eq!(
+            KeyPackage::from_bytes(&malformed.to_bytes()),
+            Err(NoistError::VssMismatch(secret_shares[0].index))
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_codec_serde() -> Result<(), NoistError> {
+        let (secret_shares, _, vss_commitments) = secret_share_gen(random_scalar()?, 3, 2)?;
+
+        let json = serde_json::to_string(&vss_commitments).unwrap();
+        assert_eq!(
+            json,
+            format!("\"{}\"", hex::encode(vss_commitments.to_bytes()))
+        );
+
+        let decoded: VssCommitment = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, vss_commitments);
+
+        let json = serde_json::to_string(&secret_shares[0]).unwrap();
+        let decoded: SecretShare = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, secret_shares[0]);
+
+        assert!(serde_json::from_str::<SecretShare>("\"0102\"").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+use noist::{
+    error::NoistError,
+    nonce::{nonce_deal, NonceEntry, SlotNonce},
+    share::ParticipantIndex,
+};
+
+/// Indexes (1, ..., n) of every participant, e.g. as the agreed dealer set.
+pub fn dealers(num_participants: u32) -> Result<Vec<ParticipantIndex>, NoistError> {
+    (1..=num_participants).map(ParticipantIndex::new).collect()
+}
+
+/// Runs `deal` once per dealer, then hands each participant, in index order, the public dealing
+/// of every dealer together with the shares that dealer sent to it.
+pub fn deal_all<T, D: Clone, S: Clone>(
+    dealers: impl IntoIterator<Item = T>,
+    deal: impl FnMut(T) -> Result<(D, Vec<S>), NoistError>,
+) -> Result<Vec<Vec<(D, S)>>, NoistError> {
+    let dealt = dealers
+        .into_iter()
+        .map(deal)
+        .collect::<Result<Vec<_>, NoistError>>()?;
+
+    let num_participants = dealt.first().map_or(0, |(_, shares)| shares.len());
+
+    Ok((0..num_participants)
+        .map(|participant| {
+            dealt
+                .iter()
+                .map(|(dealing, shares)| (dealing.clone(), shares[participant].clone()))
+                .collect()
+        })
+        .collect())
+}
+
+/// Nonce of a single slot dealt to every participant by one dealer, for tests that sign without
+/// running a nonce pool.
+pub fn nonce_slot(
+    num_participants: u32,
+    threshold: u32,
+) -> Result<(Vec<NonceEntry>, SlotNonce), NoistError> {
+    let (dealing, shares) =
+        nonce_deal(ParticipantIndex::new(1)?, 0, 1, num_participants, threshold)?;
+
+    let slot_nonce = SlotNonce {
+        slot: dealing.start,
+        vss_commitments: dealing.vss_commitments[0].clone(),
+    };
+
+    let entries = shares
+        .into_iter()
+        .map(|mut shares| NonceEntry {
+            nonce_share: shares.remove(0),
+            slot_nonce: slot_nonce.clone(),
+        })
+        .collect();
+
+    Ok((entries, slot_nonce))
+}
@@ -0,0 +1,123 @@
+mod complaint_tests {
+    use noist::{
+        complaint::{complaint_create, complaint_resolve, Verdict},
+        error::NoistError,
+        secret::secret_share_gen,
+        share::ParticipantIndex,
+        vse::{vse_encrypt_share, vse_verify_share, EncryptedShare},
+        vss::vss_public_share,
+    };
+    use secp::Scalar;
+
+    #[test]
+    fn test_complaint_resolve() -> Result<(), NoistError> {
+        let accuser_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let accuser_public = accuser_secret.base_point_mul();
+
+        let dealer = ParticipantIndex::new(1)?;
+        let accuser = ParticipantIndex::new(2)?;
+
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, _, vss_commitments) = secret_share_gen(secret, 3, 2)?;
+
+        // Dealer encrypts a bad share.
+        let bad_share = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let encrypted_bad_share = vse_encrypt_share(bad_share, accuser_public)?;
+
+        let complaint = complaint_create(accuser, dealer, accuser_secret, &encrypted_bad_share)?;
+
+        assert_eq!(
+            complaint_resolve(
+                &complaint,
+                accuser_public,
+                &encrypted_bad_share,
+                &vss_commitments
+            ),
+            Verdict::DealerFaulty(dealer)
+        );
+
+        // Accuser complains about a good share.
+        let encrypted_good_share =
+            vse_encrypt_share(secret_shares[1].secret.expose(), accuser_public)?;
+
+        let complaint = complaint_create(accuser, dealer, accuser_secret, &encrypted_good_share)?;
+
+        assert_eq!(
+            complaint_resolve(
+                &complaint,
+                accuser_public,
+                &encrypted_good_share,
+                &vss_commitments
+            ),
+            Verdict::AccuserFaulty(accuser)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_complaint_undecryptable() -> Result<(), NoistError> {
+        let accuser_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let accuser_public = accuser_secret.base_point_mul();
+
+        let dealer = ParticipantIndex::new(1)?;
+        let accuser = ParticipantIndex::new(2)?;
+
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, _, vss_commitments) = secret_share_gen(secret, 3, 2)?;
+
+        // Dealer commits to a pad unrelated to the ephemeral key, which passes the public check.
+        let pad = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let ephemeral_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let encrypted = EncryptedShare {
+            ephemeral_key: ephemeral_secret.base_point_mul(),
+            pad_commitment: pad.base_point_mul(),
+            encrypted_share: (secret_shares[1].secret.expose() + pad).unwrap(),
+        };
+
+        assert!(vse_verify_share(
+            &encrypted,
+            vss_public_share(accuser, &vss_commitments)?.point
+        ));
+
+        let complaint = complaint_create(accuser, dealer, accuser_secret, &encrypted)?;
+
+        assert_eq!(
+            complaint_resolve(&complaint, accuser_public, &encrypted, &vss_commitments),
+            Verdict::DealerFaulty(dealer)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_complaint_forged() -> Result<(), NoistError> {
+        let accuser_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let accuser_public = accuser_secret.base_point_mul();
+
+        let dealer = ParticipantIndex::new(1)?;
+        let accuser = ParticipantIndex::new(2)?;
+
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, _, vss_commitments) = secret_share_gen(secret, 3, 2)?;
+
+        let encrypted_share = vse_encrypt_share(secret_shares[1].secret.expose(), accuser_public)?;
+
+        // Accuser reveals an ECDH point derived from a different key.
+        let other_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let complaint = complaint_create(accuser, dealer, other_secret, &encrypted_share)?;
+
+        assert_eq!(
+            complaint_resolve(
+                &complaint,
+                accuser_public,
+                &encrypted_share,
+                &vss_commitments
+            ),
+            Verdict::AccuserFaulty(accuser)
+        );
+
+        Ok(())
+    }
+}
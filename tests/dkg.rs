@@ -0,0 +1,191 @@
+mod common;
+
+mod dkg_tests {
+    use noist::{
+        dkg::{dkg_deal, dkg_finalize, dkg_verify_commitment, DKGCommitment, KeyPackage},
+        error::NoistError,
+        secret::secret_share_combine,
+        share::{ParticipantIndex, SecretShare},
+        vss::vss_verify_secret,
+    };
+
+    use crate::common::{deal_all, dealers};
+
+    const CONTEXT: &[u8] = b"session";
+
+    type Received = Vec<Vec<(DKGCommitment, SecretShare)>>;
+
+    fn run_dkg(num_participants: u32, threshold: u32) -> Result<Received, NoistError> {
+        deal_all(dealers(num_participants)?, |dealer| {
+            dkg_deal(dealer, num_participants, threshold, CONTEXT)
+        })
+    }
+
+    #[test]
+    fn test_dkg() -> Result<(), NoistError> {
+        let received = run_dkg(5, 3)?;
+
+        for (commitment, _) in received[0].iter() {
+            assert!(dkg_verify_commitment(commitment, CONTEXT));
+        }
+
+        let mut key_packages = Vec::<KeyPackage>::new();
+
+        for (participant, dealings) in dealers(5)?.into_iter().zip(received.iter()) {
+            key_packages.push(dkg_finalize(
+                participant,
+                &dealers(5)?,
+                dealings,
+                3,
+                CONTEXT,
+            )?);
+        }
+
+        let group_key = key_packages[0].group_key;
+
+        for key_package in key_packages.iter() {
+            assert_eq!(key_package.group_key, group_key);
+            assert!(vss_verify_secret(
+                &key_package.secret_share,
+                &key_package.vss_commitments
+            ));
+        }
+
+        // Any t final shares reconstruct the group secret.
+        let shares: Vec<SecretShare> = key_packages[1..4]
+            .iter()
+            .map(|key_package| key_package.secret_share.clone())
+            .collect();
+
+        let group_secret = secret_share_combine(&shares, 3)?;
+        assert_eq!(group_secret.base_point_mul(), group_key);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dkg_invalid_share() -> Result<(), NoistError> {
+        let mut received = run_dkg(3, 2)?;
+
+        // Dealer 2 sends a corrupted share to participant 1.
+        received[0][1].1.secret = received[1][1].1.secret.clone();
+
+        assert_eq!(
+            dkg_finalize(
+                ParticipantIndex::new(1)?,
+                &dealers(3)?,
+                &received[0],
+                2,
+                CONTEXT
+            )
+            .map(|_| ()),
+            Err(NoistError::VssMismatch(ParticipantIndex::new(2)?))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dkg_invalid_proof() -> Result<(), NoistError> {
+        let received = run_dkg(3, 2)?;
+
+        assert_eq!(
+            dkg_finalize(
+                ParticipantIndex::new(1)?,
+                &dealers(3)?,
+                &received[0],
+                2,
+                b"other session"
+            )
+            .map(|_| ()),
+            Err(NoistError::InvalidProof(ParticipantIndex::new(1)?))
+        );
+
+        // Proof is bound to the session context, so it cannot be replayed in another session.
+        let mut commitment = received[0][0].0.clone();
+
+        assert!(!dkg_verify_commitment(&commitment, b"other session"));
+
+        // Proof is bound to the dealer index.
+        commitment.index = ParticipantIndex::new(2)?;
+
+        assert!(!dkg_verify_commitment(&commitment, CONTEXT));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dkg_dealer_set() -> Result<(), NoistError> {
+        let dealings = &run_dkg(4, 2)?[0];
+
+        // Agreed set is dealers 1, 2 and 3.
+        let agreed = [
+            ParticipantIndex::new(1)?,
+            ParticipantIndex::new(2)?,
+            ParticipantIndex::new(3)?,
+        ];
+
+        dkg_finalize(
+            ParticipantIndex::new(1)?,
+            &agreed,
+            &dealings[..3],
+            2,
+            CONTEXT,
+        )?;
+
+        // Dealing from outside the agreed set.
+        let mismatched = [
+            dealings[0].clone(),
+            dealings[1].clone(),
+            dealings[3].clone(),
+        ];
+
+        assert_eq!(
+            dkg_finalize(ParticipantIndex::new(1)?, &agreed, &mismatched, 2, CONTEXT).map(|_| ()),
+            Err(NoistError::UnknownIndex(ParticipantIndex::new(4)?))
+        );
+
+        // Subset of the agreed set, even if above the threshold.
+        assert_eq!(
+            dkg_finalize(
+                ParticipantIndex::new(1)?,
+                &agreed,
+                &dealings[..2],
+                2,
+                CONTEXT
+            )
+            .map(|_| ()),
+            Err(NoistError::MissingIndex(ParticipantIndex::new(3)?))
+        );
+
+        // Same dealing twice.
+        let duplicated = [
+            dealings[0].clone(),
+            dealings[1].clone(),
+            dealings[1].clone(),
+        ];
+
+        assert_eq!(
+            dkg_finalize(ParticipantIndex::new(1)?, &agreed, &duplicated, 2, CONTEXT).map(|_| ()),
+            Err(NoistError::DuplicateIndex(ParticipantIndex::new(2)?))
+        );
+
+        // Agreed set smaller than the threshold.
+        assert_eq!(
+            dkg_finalize(
+                ParticipantIndex::new(1)?,
+                &agreed[..1],
+                &dealings[..1],
+                2,
+                CONTEXT
+            )
+            .map(|_| ()),
+            Err(NoistError::InsufficientShares {
+                required: 2,
+                provided: 1
+            })
+        );
+
+        Ok(())
+    }
+}
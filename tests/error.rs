@@ -0,0 +1,71 @@
+mod error_tests {
+    use noist::{
+        error::NoistError,
+        into::{IntoByteArray, IntoScalar},
+        schnorr::{verify_schnorr, SignFlag},
+        share::ParticipantIndex,
+    };
+
+    #[test]
+    fn test_error_display() -> Result<(), NoistError> {
+        let error = NoistError::InsufficientShares {
+            required: 3,
+            provided: 2,
+        };
+        assert_eq!(
+            error.to_string(),
+            "insufficient shares: required 3, provided 2"
+        );
+
+        let error = NoistError::VssMismatch(ParticipantIndex::new(4)?);
+        assert_eq!(
+            error.to_string(),
+            "share from participant 4 does not match its VSS commitments"
+        );
+
+        // Usable as a boxed standard error.
+        let boxed: Box<dyn std::error::Error> = Box::new(NoistError::ZeroThreshold);
+        assert_eq!(boxed.to_string(), "threshold must be non-zero");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            vec![0u8; 31].into_byte_array_32(),
+            Err(NoistError::InvalidLength {
+                expected: 32,
+                found: 31
+            })
+        );
+
+        assert_eq!(
+            vec![0u8; 33].into_scalar(),
+            Err(NoistError::InvalidLength {
+                expected: 32,
+                found: 33
+            })
+        );
+
+        let public_key =
+            hex::decode("dee61ab0f4cb3a993cb13c552e44f5abfbf1b377c08b0380da14de41234ea8bd")
+                .unwrap()
+                .into_byte_array_32()
+                .unwrap();
+
+        // Nonce (R) that is not on the curve fails at offset 0.
+        let mut signature = [0xffu8; 64];
+        assert_eq!(
+            verify_schnorr(public_key, [0u8; 32], signature, SignFlag::BIP0340Sign),
+            Err(NoistError::ParseError { offset: 0 })
+        );
+
+        // Commitment (s) above the curve order fails at offset 32.
+        signature[..32].copy_from_slice(&public_key);
+        assert_eq!(
+            verify_schnorr(public_key, [0u8; 32], signature, SignFlag::BIP0340Sign),
+            Err(NoistError::ParseError { offset: 32 })
+        );
+    }
+}
@@ -0,0 +1,231 @@
+mod common;
+
+mod nonce_tests {
+    use noist::{
+        error::NoistError,
+        nonce::{nonce_deal, NoncePool},
+        schnorr::{verify_schnorr, SignFlag},
+        secret::secret_share_gen,
+        session::{partial_sig_aggregate, partial_sign, PartialSignature},
+        share::ParticipantIndex,
+    };
+    use secp::Scalar;
+
+    use crate::common::{deal_all, dealers};
+
+    fn replenish_all(
+        pools: &mut [NoncePool],
+        count: usize,
+        threshold: u32,
+    ) -> Result<(), NoistError> {
+        let num_participants = pools.len() as u32;
+        let start = pools[0].end();
+
+        let dealers = dealers(num_participants)?;
+
+        let received = deal_all(dealers.iter().copied(), |dealer| {
+            nonce_deal(dealer, start, count, num_participants, threshold)
+        })?;
+
+        for (pool, dealings) in pools.iter_mut().zip(received.iter()) {
+            pool.replenish(&dealers, dealings)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonce_pool_sign() -> Result<(), NoistError> {
+        let mut pools: Vec<NoncePool> = (1..=3)
+            .map(|i| Ok(NoncePool::new(ParticipantIndex::new(i)?, 2)))
+            .collect::<Result<_, NoistError>>()?;
+
+        replenish_all(&mut pools, 4, 2)?;
+
+        for pool in pools.iter() {
+            assert_eq!(pool.remaining(), 4);
+            assert_eq!(pool.end(), 4);
+        }
+
+        let secret_key = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, group_key, _) = secret_share_gen(secret_key, 3, 2)?;
+
+        let message = [0x42; 32];
+
+        // Signers 1 and 3 sign with nonce slot 2.
+        let mut partial_sigs = Vec::<PartialSignature>::new();
+        let slot_nonce = pools[0].get(2).unwrap().slot_nonce.clone();
+
+        for participant in [0, 2] {
+            let entry = pools[participant].consume(2)?;
+
+            partial_sigs.push(partial_sign(
+                group_key,
+                &secret_shares[participant],
+                &entry,
+                &message,
+                SignFlag::BIP0340Sign,
+            )?);
+        }
+
+        let signature = partial_sig_aggregate(&slot_nonce, &partial_sigs, &message, 2)?;
+
+        verify_schnorr(
+            group_key.serialize_xonly(),
+            message,
+            signature,
+            SignFlag::BIP0340Sign,
+        )?;
+
+        // A consumed nonce can never be used again.
+        assert_eq!(
+            pools[0].consume(2).map(|_| ()),
+            Err(NoistError::NonceUnavailable(2))
+        );
+        assert_eq!(pools[0].remaining(), 3);
+
+        // Every participant agrees on the nonce commitments of the remaining slots.
+        assert_eq!(
+            pools[0].get(3).unwrap().slot_nonce,
+            pools[1].get(3).unwrap().slot_nonce
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonce_pool_binding() -> Result<(), NoistError> {
+        let mut pools: Vec<NoncePool> = (1..=3)
+            .map(|i| Ok(NoncePool::new(ParticipantIndex::new(i)?, 2)))
+            .collect::<Result<_, NoistError>>()?;
+
+        replenish_all(&mut pools, 2, 2)?;
+
+        let slot_nonce = &pools[0].get(0).unwrap().slot_nonce;
+
+        // Group nonce (R) of a slot is only fixed once bound to the message.
+        let group_nonce = slot_nonce.group_nonce(&[0x01; 32])?;
+        assert_ne!(group_nonce, slot_nonce.group_nonce(&[0x02; 32])?);
+        assert_eq!(group_nonce, slot_nonce.group_nonce(&[0x01; 32])?);
+
+        // Same message at another slot gives another group nonce.
+        assert_ne!(
+            group_nonce,
+            pools[0]
+                .get(1)
+                .unwrap()
+                .slot_nonce
+                .group_nonce(&[0x01; 32])?
+        );
+
+        // Public nonce shares interpolate to the same bound group nonce.
+        for pool in pools.iter() {
+            let entry = pool.get(0).unwrap();
+
+            assert_eq!(
+                entry
+                    .slot_nonce
+                    .public_nonce_share(pool.index(), &[0x01; 32])?,
+                slot_nonce.public_nonce_share(pool.index(), &[0x01; 32])?
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonce_pool_exhaustion() -> Result<(), NoistError> {
+        let mut pools: Vec<NoncePool> = (1..=3)
+            .map(|i| Ok(NoncePool::new(ParticipantIndex::new(i)?, 2)))
+            .collect::<Result<_, NoistError>>()?;
+
+        assert!(pools[0].is_exhausted());
+
+        replenish_all(&mut pools, 2, 2)?;
+
+        pools[0].consume(0)?;
+        pools[0].consume(1)?;
+
+        assert!(pools[0].is_exhausted());
+        assert!(pools[0].consume(2).is_err());
+
+        replenish_all(&mut pools, 2, 2)?;
+
+        assert_eq!(pools[0].remaining(), 2);
+        assert_eq!(pools[0].end(), 4);
+        assert!(pools[0].get(2).is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonce_pool_invalid_dealing() -> Result<(), NoistError> {
+        let mut pool = NoncePool::new(ParticipantIndex::new(1)?, 2);
+
+        let (dealing_1, shares_1) = nonce_deal(ParticipantIndex::new(1)?, 0, 2, 3, 2)?;
+        let (dealing_2, mut shares_2) = nonce_deal(ParticipantIndex::new(2)?, 0, 2, 3, 2)?;
+
+        // Dealer 2 sends a share that does not match its commitments.
+        shares_2[0][1].binding.secret = shares_2[1][1].binding.secret.clone();
+
+        let received = vec![
+            (dealing_1.clone(), shares_1[0].clone()),
+            (dealing_2, shares_2[0].clone()),
+        ];
+        assert_eq!(
+            pool.replenish(
+                &[ParticipantIndex::new(1)?, ParticipantIndex::new(2)?],
+                &received
+            ),
+            Err(NoistError::VssMismatch(ParticipantIndex::new(2)?))
+        );
+
+        // Dealings must continue from the end of the pool.
+        let (dealing_3, shares_3) = nonce_deal(ParticipantIndex::new(3)?, 5, 2, 3, 2)?;
+
+        let received = vec![
+            (dealing_1, shares_1[0].clone()),
+            (dealing_3, shares_3[0].clone()),
+        ];
+        assert_eq!(
+            pool.replenish(
+                &[ParticipantIndex::new(1)?, ParticipantIndex::new(3)?],
+                &received
+            ),
+            Err(NoistError::NonceRangeMismatch {
+                expected: 0,
+                found: 5
+            })
+        );
+
+        assert!(pool.is_exhausted());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonce_pool_dealer_set() -> Result<(), NoistError> {
+        let mut pool = NoncePool::new(ParticipantIndex::new(1)?, 2);
+
+        let received =
+            deal_all(dealers(3)?, |dealer| nonce_deal(dealer, 0, 2, 3, 2))?.swap_remove(0);
+
+        let agreed = [ParticipantIndex::new(1)?, ParticipantIndex::new(2)?];
+
+        // Dealings from a different subset than the agreed one, even if above the threshold.
+        assert_eq!(
+            pool.replenish(&agreed, &[received[0].clone(), received[2].clone()]),
+            Err(NoistError::UnknownIndex(ParticipantIndex::new(3)?))
+        );
+        assert_eq!(
+            pool.replenish(&agreed, &received[..1]),
+            Err(NoistError::MissingIndex(ParticipantIndex::new(2)?))
+        );
+        assert!(pool.is_exhausted());
+
+        pool.replenish(&agreed, &received[..2])?;
+        assert_eq!(pool.remaining(), 2);
+
+        Ok(())
+    }
+}
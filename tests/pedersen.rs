@@ -0,0 +1,97 @@
+mod pedersen_tests {
+    use noist::{
+        error::NoistError,
+        pedersen::{
+            pedersen_commit, pedersen_generator, pedersen_share_gen, pedersen_verify_secret,
+            PedersenShare,
+        },
+        secret::{random_scalar, secret_share_combine},
+        share::SecretShare,
+        vss::vss_commit,
+    };
+    use secp::Point;
+
+    #[test]
+    fn test_pedersen_generator() {
+        let generator = pedersen_generator();
+
+        // Fixed, independent of G, and stable across calls.
+        assert_ne!(generator, Point::generator());
+        assert_eq!(generator, pedersen_generator());
+    }
+
+    #[test]
+    fn test_pedersen_share_gen() -> Result<(), NoistError> {
+        let secret = random_scalar()?;
+        let (pedersen_shares, pedersen_commitments) = pedersen_share_gen(secret, 5, 3)?;
+
+        assert_eq!(pedersen_commitments.threshold(), 3);
+
+        for share in pedersen_shares.iter() {
+            assert!(pedersen_verify_secret(share, &pedersen_commitments));
+        }
+
+        // Commitments do not reveal sG.
+        assert!(!pedersen_commitments
+            .points()
+            .contains(&secret.base_point_mul()));
+
+        // Secret is recovered from the plain shares.
+        let secret_shares: Vec<SecretShare> = pedersen_shares[1..4]
+            .iter()
+            .map(|share| share.secret_share())
+            .collect();
+
+        assert_eq!(secret_share_combine(&secret_shares, 3)?, secret);
+
+        // Tampering with either part of a share is caught.
+        let share = pedersen_shares[0].clone();
+
+        let tampered = PedersenShare::new(share.index, random_scalar()?, share.blinding.expose());
+        assert!(!pedersen_verify_secret(&tampered, &pedersen_commitments));
+
+        let tampered = PedersenShare::new(share.index, share.secret.expose(), random_scalar()?);
+        assert!(!pedersen_verify_secret(&tampered, &pedersen_commitments));
+
+        let tampered = PedersenShare::new(
+            pedersen_shares[1].index,
+            share.secret.expose(),
+            share.blinding.expose(),
+        );
+        assert!(!pedersen_verify_secret(&tampered, &pedersen_commitments));
+
+        assert!(format!("{:?}", share).contains("redacted"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pedersen_commit() -> Result<(), NoistError> {
+        let coeffs = [random_scalar()?, random_scalar()?];
+        let blinding_coeffs = [random_scalar()?, random_scalar()?];
+
+        let pedersen_commitments = pedersen_commit(&coeffs, &blinding_coeffs)?;
+
+        // Commitment is the Feldman commitment shifted by b_j*H.
+        let vss_commitments = vss_commit(&coeffs)?;
+
+        for (j, point) in pedersen_commitments.points().iter().enumerate() {
+            assert_eq!(
+                *point,
+                (vss_commitments.points()[j] + blinding_coeffs[j] * pedersen_generator())
+                    .not_inf()
+                    .unwrap()
+            );
+        }
+
+        assert_eq!(
+            pedersen_commit(&coeffs, &blinding_coeffs[..1]),
+            Err(NoistError::ThresholdMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+
+        Ok(())
+    }
+}
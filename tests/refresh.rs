@@ -0,0 +1,153 @@
+mod common;
+
+mod refresh_tests {
+    use noist::{
+        dkg::KeyPackage,
+        error::NoistError,
+        refresh::{refresh_deal, refresh_finalize, RefreshDealing},
+        secret::{secret_share_combine, secret_share_gen},
+        share::{ParticipantIndex, SecretShare},
+    };
+    use secp::Scalar;
+
+    use crate::common::{deal_all, dealers};
+
+    fn key_packages(
+        secret: Scalar,
+        num_participants: u32,
+        threshold: u32,
+    ) -> Result<Vec<KeyPackage>, NoistError> {
+        let (secret_shares, _, vss_commitments) =
+            secret_share_gen(secret, num_participants, threshold)?;
+
+        Ok(secret_shares
+            .into_iter()
+            .map(|secret_share| KeyPackage::new(secret_share, vss_commitments.clone()))
+            .collect())
+    }
+
+    type Received = Vec<Vec<(RefreshDealing, SecretShare)>>;
+
+    fn run_refresh(num_participants: u32, threshold: u32) -> Result<Received, NoistError> {
+        deal_all(dealers(num_participants)?, |dealer| {
+            refresh_deal(dealer, num_participants, threshold)
+        })
+    }
+
+    #[test]
+    fn test_refresh() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let old_packages = key_packages(secret, 5, 3)?;
+
+        let received = run_refresh(5, 3)?;
+
+        let mut new_packages = Vec::<KeyPackage>::new();
+
+        for (old_package, dealings) in old_packages.iter().zip(received.iter()) {
+            new_packages.push(refresh_finalize(old_package, &dealers(5)?, dealings)?);
+        }
+
+        for (old_package, new_package) in old_packages.iter().zip(new_packages.iter()) {
+            assert_eq!(new_package.group_key, old_package.group_key);
+            assert_eq!(new_package.vss_commitments, new_packages[0].vss_commitments);
+            assert_ne!(new_package.secret_share, old_package.secret_share);
+        }
+
+        // Refreshed shares still reconstruct the same secret.
+        let shares: Vec<SecretShare> = new_packages[2..5]
+            .iter()
+            .map(|key_package| key_package.secret_share.clone())
+            .collect();
+
+        assert_eq!(secret_share_combine(&shares, 3)?, secret);
+
+        // Mixing old and refreshed shares does not.
+        let shares = vec![
+            old_packages[0].secret_share.clone(),
+            new_packages[1].secret_share.clone(),
+            new_packages[2].secret_share.clone(),
+        ];
+
+        assert_ne!(secret_share_combine(&shares, 3)?, secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_refresh_invalid_share() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let old_packages = key_packages(secret, 3, 2)?;
+
+        let mut received = run_refresh(3, 2)?.swap_remove(0);
+
+        // Dealer 2 sends a bad share to participant 1.
+        received[1].1 = SecretShare::new(
+            received[1].1.index,
+            Scalar::reduce_from(&rand::random::<[u8; 32]>()),
+        );
+
+        assert_eq!(
+            refresh_finalize(&old_packages[0], &dealers(3)?, &received).unwrap_err(),
+            NoistError::VssMismatch(ParticipantIndex::new(2)?)
+        );
+
+        // Dealer 2 deals for a different threshold.
+        let (dealing, shares) = refresh_deal(ParticipantIndex::new(2)?, 3, 3)?;
+
+        received[1] = (dealing, shares[0].clone());
+
+        assert_eq!(
+            refresh_finalize(&old_packages[0], &dealers(3)?, &received).unwrap_err(),
+            NoistError::ThresholdMismatch {
+                expected: 2,
+                found: 3
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_refresh_dealer_set() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let old_packages = key_packages(secret, 3, 2)?;
+
+        let received = run_refresh(3, 2)?.swap_remove(0);
+
+        // A single dealing is not enough to refresh.
+        assert_eq!(
+            refresh_finalize(&old_packages[0], &dealers(1)?, &received[..1]).unwrap_err(),
+            NoistError::InsufficientShares {
+                required: 2,
+                provided: 1
+            }
+        );
+
+        // Dealings must match the agreed set exactly.
+        assert_eq!(
+            refresh_finalize(&old_packages[0], &dealers(3)?, &received[..2]).unwrap_err(),
+            NoistError::MissingIndex(ParticipantIndex::new(3)?)
+        );
+
+        assert_eq!(
+            refresh_finalize(&old_packages[0], &dealers(2)?, &received).unwrap_err(),
+            NoistError::UnknownIndex(ParticipantIndex::new(3)?)
+        );
+
+        // Refreshing needs a threshold of at least 2.
+        assert_eq!(
+            refresh_deal(ParticipantIndex::new(1)?, 3, 1).unwrap_err(),
+            NoistError::ThresholdTooLow {
+                threshold: 1,
+                minimum: 2
+            }
+        );
+
+        assert_eq!(
+            refresh_deal(ParticipantIndex::new(1)?, 3, 0).unwrap_err(),
+            NoistError::ZeroThreshold
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+mod repair_tests {
+    use noist::{
+        error::NoistError,
+        repair::{
+            enroll_finalize, enroll_index, repair_combine, repair_deal, repair_finalize,
+            RepairContribution,
+        },
+        secret::secret_share_gen,
+        sensitive::SecretScalar,
+        share::{ParticipantIndex, SecretShare},
+        vss::VssCommitment,
+    };
+    use secp::Scalar;
+
+    fn run_repair(
+        secret_shares: &[SecretShare],
+        helpers: &[ParticipantIndex],
+        lost_index: ParticipantIndex,
+    ) -> Result<Vec<RepairContribution>, NoistError> {
+        let helper_shares: Vec<&SecretShare> = secret_shares
+            .iter()
+            .filter(|share| helpers.contains(&share.index))
+            .collect();
+
+        let mut dealt = Vec::<RepairContribution>::new();
+
+        for share in helper_shares.iter() {
+            dealt.extend(repair_deal(share, helpers, lost_index)?);
+        }
+
+        let mut sigmas = Vec::<RepairContribution>::new();
+
+        for helper in helpers {
+            let received: Vec<RepairContribution> = dealt
+                .iter()
+                .filter(|contribution| contribution.to == *helper)
+                .cloned()
+                .collect();
+
+            sigmas.push(repair_combine(*helper, helpers, lost_index, &received)?);
+        }
+
+        Ok(sigmas)
+    }
+
+    fn setup() -> Result<(Vec<SecretShare>, VssCommitment), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, _, vss_commitments) = secret_share_gen(secret, 5, 3)?;
+
+        Ok((secret_shares, vss_commitments))
+    }
+
+    #[test]
+    fn test_repair() -> Result<(), NoistError> {
+        let (secret_shares, vss_commitments) = setup()?;
+
+        // Participant 2 lost their share, helpers 1, 4 and 5 repair it.
+        let lost_index = ParticipantIndex::new(2)?;
+        let helpers = [
+            ParticipantIndex::new(1)?,
+            ParticipantIndex::new(4)?,
+            ParticipantIndex::new(5)?,
+        ];
+
+        let sigmas = run_repair(&secret_shares, &helpers, lost_index)?;
+
+        let repaired = repair_finalize(lost_index, &helpers, &sigmas, &vss_commitments)?;
+
+        assert_eq!(repaired, secret_shares[1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_repair_invalid() -> Result<(), NoistError> {
+        let (secret_shares, vss_commitments) = setup()?;
+
+        let lost_index = ParticipantIndex::new(3)?;
+        let helpers = [ParticipantIndex::new(1)?, ParticipantIndex::new(2)?];
+
+        // Fewer helpers than the threshold.
+        let sigmas = run_repair(&secret_shares, &helpers, lost_index)?;
+
+        assert_eq!(
+            repair_finalize(lost_index, &helpers, &sigmas, &vss_commitments),
+            Err(NoistError::InsufficientShares {
+                required: 3,
+                provided: 2
+            })
+        );
+
+        // Tampered contribution is caught by the VSS commitments.
+        let helpers = [
+            ParticipantIndex::new(1)?,
+            ParticipantIndex::new(2)?,
+            ParticipantIndex::new(4)?,
+        ];
+
+        let mut sigmas = run_repair(&secret_shares, &helpers, lost_index)?;
+        sigmas[0].value = SecretScalar::random()?;
+
+        assert_eq!(
+            repair_finalize(lost_index, &helpers, &sigmas, &vss_commitments),
+            Err(NoistError::VssMismatch(lost_index))
+        );
+
+        // Lost participant cannot help repair itself.
+        assert_eq!(
+            repair_deal(&secret_shares[0], &[helpers[0], lost_index], lost_index),
+            Err(NoistError::DuplicateIndex(lost_index))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_enroll() -> Result<(), NoistError> {
+        // Group of 5 evaluated one index further, to compare the enrolled share against f(6).
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, group_key, vss_commitments) = secret_share_gen(secret, 6, 3)?;
+
+        let new_index = enroll_index(5)?;
+        assert_eq!(new_index, secret_shares[5].index);
+
+        assert_eq!(
+            enroll_index(u32::MAX),
+            Err(NoistError::IndexExhausted {
+                num_participants: u32::MAX
+            })
+        );
+
+        let helpers = [
+            ParticipantIndex::new(2)?,
+            ParticipantIndex::new(3)?,
+            ParticipantIndex::new(5)?,
+        ];
+
+        let sigmas = run_repair(&secret_shares[..5], &helpers, new_index)?;
+
+        let key_package = enroll_finalize(5, &helpers, &sigmas, &vss_commitments)?;
+
+        assert_eq!(key_package.secret_share, secret_shares[5]);
+        assert_eq!(key_package.group_key, group_key);
+
+        // Contributions for another index do not verify.
+        let sigmas = run_repair(&secret_shares[..5], &helpers, ParticipantIndex::new(7)?)?;
+
+        assert_eq!(
+            enroll_finalize(5, &helpers, &sigmas, &vss_commitments).unwrap_err(),
+            NoistError::IndexMismatch {
+                expected: new_index,
+                found: ParticipantIndex::new(7)?
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+mod common;
+
+mod reshare_tests {
+    use noist::{
+        dkg::KeyPackage,
+        error::NoistError,
+        reshare::{reshare_deal, reshare_finalize, ReshareDealing},
+        schnorr::{verify_schnorr, SignFlag},
+        secret::{secret_share_combine, secret_share_gen},
+        session::{partial_sig_aggregate, partial_sign, PartialSignature},
+        share::{ParticipantIndex, SecretShare},
+    };
+    use secp::Scalar;
+
+    use crate::common::{deal_all, nonce_slot};
+
+    fn dealer_indexes(old_shares: &[SecretShare]) -> Vec<ParticipantIndex> {
+        old_shares.iter().map(|share| share.index).collect()
+    }
+
+    type Received = Vec<Vec<(ReshareDealing, SecretShare)>>;
+
+    fn run_reshare(
+        old_shares: &[SecretShare],
+        new_num_participants: u32,
+        new_threshold: u32,
+    ) -> Result<Received, NoistError> {
+        let dealers = dealer_indexes(old_shares);
+
+        deal_all(old_shares, |old_share| {
+            reshare_deal(old_share, &dealers, new_num_participants, new_threshold)
+        })
+    }
+
+    #[test]
+    fn test_reshare() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (old_shares, group_key, old_vss_commitments) = secret_share_gen(secret, 5, 3)?;
+
+        // Old holders 1, 3 and 5 reshare to 7 participants with threshold 4.
+        let dealers = [
+            old_shares[0].clone(),
+            old_shares[2].clone(),
+            old_shares[4].clone(),
+        ];
+        let received = run_reshare(&dealers, 7, 4)?;
+
+        let mut new_packages = Vec::<KeyPackage>::new();
+
+        for (i, dealings) in (1..=7u32).zip(received.iter()) {
+            new_packages.push(reshare_finalize(
+                ParticipantIndex::new(i)?,
+                &dealer_indexes(&dealers),
+                dealings,
+                &old_vss_commitments,
+                4,
+            )?);
+        }
+
+        for key_package in new_packages.iter() {
+            assert_eq!(key_package.group_key, group_key);
+            assert_eq!(key_package.vss_commitments.threshold(), 4);
+            assert_eq!(key_package.vss_commitments, new_packages[0].vss_commitments);
+        }
+
+        // Any 4 new shares reconstruct the same secret.
+        let shares: Vec<SecretShare> = new_packages[3..7]
+            .iter()
+            .map(|key_package| key_package.secret_share.clone())
+            .collect();
+
+        assert_eq!(secret_share_combine(&shares, 4)?, secret);
+
+        // New shares sign under the old group key.
+        let message = rand::random::<[u8; 32]>();
+        let (nonces, slot_nonce) = nonce_slot(7, 4)?;
+
+        let mut partial_sigs = Vec::<PartialSignature>::new();
+
+        for index in [0, 2, 4, 6] {
+            partial_sigs.push(partial_sign(
+                group_key,
+                &new_packages[index].secret_share,
+                &nonces[index],
+                &message,
+                SignFlag::BIP0340Sign,
+            )?);
+        }
+
+        let signature = partial_sig_aggregate(&slot_nonce, &partial_sigs, &message, 4)?;
+
+        verify_schnorr(
+            group_key.serialize_xonly(),
+            message,
+            signature,
+            SignFlag::BIP0340Sign,
+        )
+    }
+
+    #[test]
+    fn test_reshare_invalid_dealing() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (old_shares, _, old_vss_commitments) = secret_share_gen(secret, 3, 2)?;
+
+        // Holder 2 deals a secret unrelated to its share.
+        let forged_share = SecretShare::new(
+            old_shares[1].index,
+            Scalar::reduce_from(&rand::random::<[u8; 32]>()),
+        );
+
+        let dealers = [old_shares[0].clone(), forged_share];
+        let received = run_reshare(&dealers, 3, 2)?.swap_remove(0);
+
+        assert_eq!(
+            reshare_finalize(
+                ParticipantIndex::new(1)?,
+                &dealer_indexes(&dealers),
+                &received,
+                &old_vss_commitments,
+                2
+            )
+            .unwrap_err(),
+            NoistError::InvalidProof(old_shares[1].index)
+        );
+
+        // Too few old holders.
+        assert_eq!(
+            reshare_finalize(
+                ParticipantIndex::new(1)?,
+                &dealer_indexes(&dealers[..1]),
+                &received[..1],
+                &old_vss_commitments,
+                2
+            )
+            .unwrap_err(),
+            NoistError::InsufficientShares {
+                required: 2,
+                provided: 1
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reshare_dealer_set() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (old_shares, group_key, old_vss_commitments) = secret_share_gen(secret, 5, 2)?;
+
+        // All 5 old holders reshare, more than the old threshold of 2.
+        let received = run_reshare(&old_shares, 3, 2)?;
+
+        let mut new_shares = Vec::<SecretShare>::new();
+
+        for (i, dealings) in (1..=3u32).zip(received.iter()) {
+            let key_package = reshare_finalize(
+                ParticipantIndex::new(i)?,
+                &dealer_indexes(&old_shares),
+                dealings,
+                &old_vss_commitments,
+                2,
+            )?;
+
+            assert_eq!(key_package.group_key, group_key);
+
+            new_shares.push(key_package.secret_share);
+        }
+
+        assert_eq!(secret_share_combine(&new_shares[1..], 2)?, secret);
+
+        // Dealings must match the agreed set exactly.
+        assert_eq!(
+            reshare_finalize(
+                ParticipantIndex::new(1)?,
+                &dealer_indexes(&old_shares),
+                &received[0][..4],
+                &old_vss_commitments,
+                2
+            )
+            .unwrap_err(),
+            NoistError::MissingIndex(old_shares[4].index)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+mod secret_tests {
+    use noist::{
+        error::NoistError,
+        secret::{random_scalar_with_rng, secret_share_gen_with_rng, RANDOM_SCALAR_ATTEMPTS},
+        share::ParticipantIndex,
+    };
+    use rand::{CryptoRng, RngCore};
+    use rand_core::impls;
+    use secp::Scalar;
+
+    const CURVE_ORDER: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
+
+    /// Replays the given 32-byte blocks, then repeats the last one forever.
+    struct ScriptedRng {
+        blocks: Vec<[u8; 32]>,
+        position: usize,
+    }
+
+    impl ScriptedRng {
+        fn new(blocks: Vec<[u8; 32]>) -> ScriptedRng {
+            ScriptedRng {
+                blocks,
+                position: 0,
+            }
+        }
+    }
+
+    impl RngCore for ScriptedRng {
+        fn next_u32(&mut self) -> u32 {
+            impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            let block = self.blocks[self.position.min(self.blocks.len() - 1)];
+            self.position += 1;
+            dest.copy_from_slice(&block[..dest.len()]);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for ScriptedRng {}
+
+    fn block(hex: &str) -> [u8; 32] {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_random_scalar_rejects_out_of_range() -> Result<(), NoistError> {
+        let order = block(CURVE_ORDER);
+        let max = [0xff; 32];
+        let zero = [0; 32];
+        let one = block("0000000000000000000000000000000000000000000000000000000000000001");
+
+        // Zero, the curve order and values above it are redrawn.
+        let mut rng = ScriptedRng::new(vec![zero, order, max, one]);
+        assert_eq!(random_scalar_with_rng(&mut rng)?, Scalar::one());
+        assert_eq!(rng.position, 4);
+
+        // Order minus one is the largest valid scalar and is accepted as is.
+        let order_minus_one =
+            block("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364140");
+        let mut rng = ScriptedRng::new(vec![order_minus_one]);
+        assert_eq!(random_scalar_with_rng(&mut rng)?, -Scalar::one());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_scalar_broken_source() -> Result<(), NoistError> {
+        // A source stuck at zero is reported instead of looping forever.
+        let mut rng = ScriptedRng::new(vec![[0; 32]]);
+        assert_eq!(
+            random_scalar_with_rng(&mut rng),
+            Err(NoistError::RngFailure)
+        );
+        assert_eq!(rng.position, RANDOM_SCALAR_ATTEMPTS);
+
+        // Dealing resamples a rejected coefficient rather than failing.
+        let two = block("0000000000000000000000000000000000000000000000000000000000000002");
+        let mut rng = ScriptedRng::new(vec![block(CURVE_ORDER), two]);
+
+        let (secret_shares, _, _) = secret_share_gen_with_rng(&mut rng, Scalar::one(), 3, 2)?;
+
+        // Polynomial is = 1 + 2x.
+        assert_eq!(
+            secret_shares[0].secret.expose(),
+            ParticipantIndex::new(3)?.to_scalar()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+mod sensitive_tests {
+    use noist::{
+        error::NoistError,
+        pedersen::pedersen_share_gen,
+        secret::{secret_share_gen, secret_share_shard, secret_share_shard_polynomial},
+        sensitive::{SecretPolynomial, SecretScalar},
+    };
+    use secp::{MaybeScalar, Scalar};
+    use zeroize::Zeroize;
+
+    #[test]
+    fn test_sensitive_debug_redacted() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let secret_hex = hex::encode(secret.serialize());
+
+        let (secret_shares, _, _) = secret_share_gen(secret, 3, 2)?;
+        let share_hex = hex::encode(secret_shares[0].secret.expose().serialize());
+
+        let (_, polynomial) = secret_share_shard(secret, &[secret], 3)?;
+
+        for debug in [
+            format!("{:?}", SecretScalar::new(secret)),
+            format!("{:?}", secret_shares[0]),
+            format!("{:?}", polynomial),
+        ] {
+            assert!(debug.contains("redacted"));
+            assert!(!debug.to_lowercase().contains(&secret_hex));
+            assert!(!debug.to_lowercase().contains(&share_hex));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sensitive_polynomial() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let coefficient = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let polynomial = SecretPolynomial::from_coefficients(secret, &[coefficient]);
+        assert_eq!(polynomial.coefficients(), &[secret, coefficient]);
+
+        // Share (y_i) is = a_0 + a_1 * i.
+        let shares = secret_share_shard_polynomial(&polynomial, 3)?;
+
+        for share in shares.iter() {
+            assert_eq!(
+                MaybeScalar::Valid(share.secret.expose()),
+                secret + coefficient * share.index.to_scalar()
+            );
+        }
+
+        let polynomial = SecretPolynomial::random(secret, 4)?;
+        assert_eq!(polynomial.len(), 5);
+        assert_eq!(polynomial[0], secret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sensitive_zeroize() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        // Wiped scalars hold the public constant one.
+        let (mut secret_shares, _, _) = secret_share_gen(secret, 3, 2)?;
+        secret_shares[0].zeroize();
+        assert_eq!(secret_shares[0].secret.expose(), Scalar::one());
+
+        let (mut pedersen_shares, _) = pedersen_share_gen(secret, 3, 2)?;
+        pedersen_shares[0].zeroize();
+        assert_eq!(pedersen_shares[0].secret.expose(), Scalar::one());
+        assert_eq!(pedersen_shares[0].blinding.expose(), Scalar::one());
+
+        let mut polynomial = SecretPolynomial::random(secret, 2)?;
+        polynomial.zeroize();
+        assert!(polynomial
+            .iter()
+            .all(|coefficient| *coefficient == Scalar::one()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+mod common;
+
+mod session_tests {
+    use noist::{
+        error::NoistError,
+        schnorr::{verify_schnorr, SignFlag},
+        secret::secret_share_gen,
+        session::{
+            partial_sig_aggregate, partial_sig_aggregate_verified, partial_sig_blame, partial_sign,
+            PartialSignature,
+        },
+    };
+    use secp::Scalar;
+
+    use crate::common::nonce_slot;
+
+    #[test]
+    fn test_threshold_sign() -> Result<(), NoistError> {
+        let message =
+            hex::decode("e97f06fabc231539119048bd3c55d0aa6015ed157532e6a5e6fb15aae331791d")
+                .unwrap();
+        let message: [u8; 32] = message.try_into().unwrap();
+
+        let secret_key = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let (secret_shares, group_key, _) = secret_share_gen(secret_key, 5, 3)?;
+        let (nonces, slot_nonce) = nonce_slot(5, 3)?;
+
+        let mut partial_sigs = Vec::<PartialSignature>::new();
+
+        // Signers 2, 4 and 5 participate.
+        for index in [1, 3, 4] {
+            partial_sigs.push(partial_sign(
+                group_key,
+                &secret_shares[index],
+                &nonces[index],
+                &message,
+                SignFlag::BIP0340Sign,
+            )?);
+        }
+
+        let signature = partial_sig_aggregate(&slot_nonce, &partial_sigs, &message, 3)?;
+
+        verify_schnorr(
+            group_key.serialize_xonly(),
+            message,
+            signature,
+            SignFlag::BIP0340Sign,
+        )
+    }
+
+    #[test]
+    fn test_threshold_sign_insufficient() -> Result<(), NoistError> {
+        let message = [0xab; 32];
+
+        let secret_key = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let (secret_shares, group_key, _) = secret_share_gen(secret_key, 5, 3)?;
+        let (nonces, slot_nonce) = nonce_slot(5, 3)?;
+
+        let mut partial_sigs = Vec::<PartialSignature>::new();
+
+        for index in [0, 1] {
+            partial_sigs.push(partial_sign(
+                group_key,
+                &secret_shares[index],
+                &nonces[index],
+                &message,
+                SignFlag::BIP0340Sign,
+            )?);
+        }
+
+        assert_eq!(
+            partial_sig_aggregate(&slot_nonce, &partial_sigs, &message, 3),
+            Err(NoistError::InsufficientShares {
+                required: 3,
+                provided: 2
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_sign_blame() -> Result<(), NoistError> {
+        let message = [0xcd; 32];
+
+        let secret_key = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let (secret_shares, group_key, key_vss_commitments) = secret_share_gen(secret_key, 5, 3)?;
+        let (nonces, slot_nonce) = nonce_slot(5, 3)?;
+
+        let mut partial_sigs = Vec::<PartialSignature>::new();
+
+        for index in [0, 1, 2, 4] {
+            partial_sigs.push(partial_sign(
+                group_key,
+                &secret_shares[index],
+                &nonces[index],
+                &message,
+                SignFlag::BIP0340Sign,
+            )?);
+        }
+
+        // Signer 2 submits a garbage partial signature.
+        partial_sigs[1].sig = partial_sigs[0].sig;
+
+        let blamed = partial_sig_blame(
+            &key_vss_commitments,
+            &slot_nonce,
+            &partial_sigs,
+            &message,
+            SignFlag::BIP0340Sign,
+        )?;
+        assert_eq!(blamed, vec![secret_shares[1].index]);
+
+        // Remaining honest signers still reach the threshold.
+        let (signature, blamed) = partial_sig_aggregate_verified(
+            &key_vss_commitments,
+            &slot_nonce,
+            &partial_sigs,
+            &message,
+            3,
+            SignFlag::BIP0340Sign,
+        )?;
+        assert_eq!(blamed, vec![secret_shares[1].index]);
+
+        verify_schnorr(
+            group_key.serialize_xonly(),
+            message,
+            signature,
+            SignFlag::BIP0340Sign,
+        )?;
+
+        // Two disruptive signers leave too few honest ones.
+        partial_sigs[2].sig = partial_sigs[0].sig;
+
+        assert!(partial_sig_aggregate_verified(
+            &key_vss_commitments,
+            &slot_nonce,
+            &partial_sigs,
+            &message,
+            3,
+            SignFlag::BIP0340Sign,
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_sign_duplicate() -> Result<(), NoistError> {
+        let message = [0xef; 32];
+
+        let secret_key = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let (secret_shares, group_key, key_vss_commitments) = secret_share_gen(secret_key, 5, 3)?;
+        let (nonces, slot_nonce) = nonce_slot(5, 3)?;
+
+        let mut partial_sigs = Vec::<PartialSignature>::new();
+
+        for index in [0, 1, 2] {
+            partial_sigs.push(partial_sign(
+                group_key,
+                &secret_shares[index],
+                &nonces[index],
+                &message,
+                SignFlag::BIP0340Sign,
+            )?);
+        }
+
+        // Signer 1 sends the same valid partial signature twice.
+        partial_sigs.insert(1, partial_sigs[0]);
+
+        let (signature, blamed) = partial_sig_aggregate_verified(
+            &key_vss_commitments,
+            &slot_nonce,
+            &partial_sigs,
+            &message,
+            3,
+            SignFlag::BIP0340Sign,
+        )?;
+        assert!(blamed.is_empty());
+
+        verify_schnorr(
+            group_key.serialize_xonly(),
+            message,
+            signature,
+            SignFlag::BIP0340Sign,
+        )
+    }
+}
@@ -0,0 +1,135 @@
+mod share_tests {
+    use noist::{
+        error::NoistError,
+        lagrance::lagrance_interpolating_value,
+        secret::{secret_share_combine, secret_share_gen},
+        share::{validate_indexes, ParticipantIndex, SecretShare},
+        vss::vss_verify_secret,
+    };
+    use secp::Scalar;
+
+    #[test]
+    fn test_participant_index() -> Result<(), NoistError> {
+        assert_eq!(ParticipantIndex::new(0), Err(NoistError::ZeroIndex));
+        assert_eq!(
+            ParticipantIndex::new_within(6, 5),
+            Err(NoistError::IndexOutOfRange {
+                index: 6,
+                num_participants: 5
+            })
+        );
+
+        let index = ParticipantIndex::new_within(5, 5)?;
+        assert_eq!(index.value(), 5);
+
+        let index_scalar: Scalar = index.into();
+        assert_eq!(
+            hex::encode(index_scalar.serialize()),
+            "0000000000000000000000000000000000000000000000000000000000000005"
+        );
+        assert_eq!(ParticipantIndex::try_from(index_scalar)?, index);
+
+        // Scalars that do not encode a small index are rejected.
+        assert!(ParticipantIndex::from_scalar(Scalar::max()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_indexes() -> Result<(), NoistError> {
+        let indexes = vec![ParticipantIndex::new(1)?, ParticipantIndex::new(3)?];
+
+        validate_indexes(&indexes, Some(3))?;
+        assert!(validate_indexes(&indexes, Some(2)).is_err());
+
+        let duplicates = vec![ParticipantIndex::new(2)?, ParticipantIndex::new(2)?];
+        assert_eq!(
+            validate_indexes(&duplicates, None),
+            Err(NoistError::DuplicateIndex(ParticipantIndex::new(2)?))
+        );
+        assert!(lagrance_interpolating_value(&duplicates, ParticipantIndex::new(2)?).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_combine_duplicate_shares() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let (shares, _, _) = secret_share_gen(secret, 5, 3)?;
+
+        assert_eq!(secret_share_combine(&shares[2..5], 3)?, secret);
+
+        // The same share counted twice must not pass as two participants.
+        let duplicated: Vec<SecretShare> =
+            vec![shares[0].clone(), shares[1].clone(), shares[1].clone()];
+        assert_eq!(
+            secret_share_combine(&duplicated, 3),
+            Err(NoistError::DuplicateIndex(shares[1].index))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_participant_index_big_endian() -> Result<(), NoistError> {
+        let index = ParticipantIndex::new(0x01020304)?;
+        assert_eq!(
+            hex::encode(index.to_scalar().serialize()),
+            "0000000000000000000000000000000000000000000000000000000001020304"
+        );
+        assert_eq!(ParticipantIndex::from_scalar(index.to_scalar())?, index);
+
+        let index = ParticipantIndex::new(256)?;
+        assert_eq!(
+            hex::encode(index.to_scalar().serialize()),
+            "0000000000000000000000000000000000000000000000000000000000000100"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_group() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let (shares, group_key, vss_commitments) = secret_share_gen(secret, 300, 50)?;
+
+        assert_eq!(shares.len(), 300);
+        assert_eq!(shares[299].index.value(), 300);
+        assert_eq!(group_key, secret.base_point_mul());
+
+        for share in shares[254..258].iter() {
+            assert!(vss_verify_secret(share, &vss_commitments));
+        }
+
+        // Any t shares, including indexes beyond 255, reconstruct the secret.
+        assert_eq!(secret_share_combine(&shares[250..300], 50)?, secret);
+        assert_eq!(
+            secret_share_combine(&shares[251..300], 50),
+            Err(NoistError::InsufficientShares {
+                required: 50,
+                provided: 49
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_threshold() {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        assert_eq!(
+            secret_share_gen(secret, 3, 0).map(|_| ()),
+            Err(NoistError::ZeroThreshold)
+        );
+        assert_eq!(
+            secret_share_gen(secret, 3, 4).map(|_| ()),
+            Err(NoistError::ThresholdExceedsParticipants {
+                threshold: 4,
+                num_participants: 3
+            })
+        );
+    }
+}
@@ -1,11 +1,14 @@
 mod single_tests {
     use noist::{
-        into::{IntoByteArray, SecpError},
-        schnorr::{sign_schnorr, verify_schnorr, SignFlag},
+        error::NoistError,
+        into::IntoByteArray,
+        schnorr::{
+            sign_schnorr, sign_schnorr_message, verify_schnorr, verify_schnorr_message, SignFlag,
+        },
     };
 
     #[test]
-    fn test_sign_schnorr_single() -> Result<(), SecpError> {
+    fn test_sign_schnorr_single() -> Result<(), NoistError> {
         let message =
             hex::decode("e97f06fabc231539119048bd3c55d0aa6015ed157532e6a5e6fb15aae331791d")
                 .unwrap();
@@ -17,12 +20,8 @@ mod single_tests {
         let sig_expected = hex::decode("47698380a92278684fe8a8f744f270fed68da78ea882a673d98c519e4e512c39065f34b452aeebf5ed276eb1100cd229bf10dadd78e4dd5b568eea12a3f7bc67").unwrap();
 
         let sig: [u8; 64] = sign_schnorr(
-            private_key
-                .into_byte_array_32()
-                .map_err(|_| SecpError::SignatureParseError)?,
-            message
-                .into_byte_array_32()
-                .map_err(|_| SecpError::SignatureParseError)?,
+            private_key.into_byte_array_32()?,
+            message.into_byte_array_32()?,
             SignFlag::BIP0340Sign,
         )?;
 
@@ -32,7 +31,7 @@ mod single_tests {
     }
 
     #[test]
-    fn test_verify_schnorr_single() -> Result<(), SecpError> {
+    fn test_verify_schnorr_single() -> Result<(), NoistError> {
         let message =
             hex::decode("e97f06fabc231539119048bd3c55d0aa6015ed157532e6a5e6fb15aae331791d")
                 .unwrap();
@@ -46,16 +45,61 @@ mod single_tests {
         let signature = hex::decode("47698380a92278684fe8a8f744f270fed68da78ea882a673d98c519e4e512c39065f34b452aeebf5ed276eb1100cd229bf10dadd78e4dd5b568eea12a3f7bc67").unwrap();
 
         verify_schnorr(
-            public_key
-                .into_byte_array_32()
-                .map_err(|_| SecpError::SignatureParseError)?,
-            message
-                .into_byte_array_32()
-                .map_err(|_| SecpError::SignatureParseError)?,
-            signature
-                .into_byte_array_64()
-                .map_err(|_| SecpError::SignatureParseError)?,
+            public_key.into_byte_array_32()?,
+            message.into_byte_array_32()?,
+            signature.into_byte_array_64()?,
             SignFlag::BIP0340Sign,
         )
     }
+
+    #[test]
+    fn test_verify_schnorr_variable_length() -> Result<(), NoistError> {
+        // BIP-340 test vectors 15-18.
+        let public_key =
+            hex::decode("778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117")
+                .unwrap()
+                .into_byte_array_32()?;
+
+        let vectors: Vec<(Vec<u8>, &str)> = vec![
+            (vec![], "71535DB165ECD9FBBC046E5FFAEA61186BB6AD436732FCCC25291A55895464CF6069CE26BF03466228F19A3A62DB8A649F2D560FAC652827D1AF0574E427AB63"),
+            (hex::decode("11").unwrap(), "08A20A0AFEF64124649232E0693C583AB1B9934AE63B4C3511F3AE1134C6A303EA3173BFEA6683BD101FA5AA5DBC1996FE7CACFC5A577D33EC14564CEC2BACBF"),
+            (hex::decode("0102030405060708090A0B0C0D0E0F1011").unwrap(), "5130F39A4059B43BC7CAC09A19ECE52B5D8699D1A71E3C52DA9AFDB6B50AC370C4A482B77BF960F8681540E25B6771ECE1E5A37FD80E5A51897C5566A97EA5A5"),
+            (vec![0x99; 100], "403B12B0D8555A344175EA7EC746566303321E5DBFA8BE6F091635163ECA79A8585ED3E3170807E7C03B720FC54C7B23897FCBA0E9D0B4A06894CFD249F22367"),
+        ];
+
+        for (message, signature) in vectors {
+            let signature = hex::decode(signature).unwrap().into_byte_array_64()?;
+
+            verify_schnorr_message(public_key, &message, signature, SignFlag::BIP0340Sign)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_schnorr_variable_length() -> Result<(), NoistError> {
+        let private_key =
+            hex::decode("09f5dde60c19101b671a5e3f4e6f0c0aaa92814170edf7f6bc19b5a21e358a51")
+                .unwrap()
+                .into_byte_array_32()?;
+        let public_key =
+            hex::decode("dee61ab0f4cb3a993cb13c552e44f5abfbf1b377c08b0380da14de41234ea8bd")
+                .unwrap()
+                .into_byte_array_32()?;
+
+        for message in [vec![], vec![0x42], vec![0x99; 100]] {
+            let signature = sign_schnorr_message(private_key, &message, SignFlag::BIP0340Sign)?;
+
+            verify_schnorr_message(public_key, &message, signature, SignFlag::BIP0340Sign)?;
+        }
+
+        // The 32-byte path matches the variable-length path bit for bit.
+        let message = [0x42; 32];
+        assert_eq!(
+            sign_schnorr(private_key, message, SignFlag::BIP0340Sign)?,
+            sign_schnorr_message(private_key, &message, SignFlag::BIP0340Sign)?
+        );
+
+        Ok(())
+    }
 }
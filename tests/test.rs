@@ -1,9 +1,17 @@
 mod core_tests {
-    use secp::Scalar;
     use noist::{
-        secret::{secret_share_combine, secret_share_gen},
-        vss::vss_verify_secret,
+        dkg::dkg_deal_with_rng,
+        nonce::nonce_deal_with_rng,
+        repair::repair_deal_with_rng,
+        secret::{
+            deterministic_rng, secret_share_combine, secret_share_gen, secret_share_gen_with_rng,
+        },
+        share::{ParticipantIndex, SecretShare},
+        transport::transport_seal_with_rng,
+        vse::{dleq_prove_with_rng, vse_encrypt_share_with_rng},
+        vss::{vss_verify_secret, vss_verify_secrets_with_rng},
     };
+    use secp::{Point, Scalar};
 
     #[test]
     fn test_main() {
@@ -12,22 +20,19 @@ mod core_tests {
 
         let (secrets, group_key, vss_commitments) = secret_share_gen(secret, 5, 3).unwrap();
 
-        println!(
-            "group key is : {}",
-            hex::encode(group_key.serialize().to_vec())
-        );
+        println!("group key is : {}", hex::encode(group_key.serialize()));
 
         for (index, secret) in secrets.iter().enumerate() {
             println!(
                 "secret share {} is : {}, {}",
                 index,
-                hex::encode(secret.0.serialize().to_vec()),
-                hex::encode(secret.1.serialize().to_vec())
+                hex::encode(secret.index.to_scalar().serialize()),
+                hex::encode(secret.secret.expose().serialize())
             );
 
             println!(
                 "vss verify: {}",
-                vss_verify_secret((secret.0, secret.1), &vss_commitments)
+                vss_verify_secret(secret, &vss_commitments)
             );
         }
 
@@ -49,14 +54,132 @@ mod core_tests {
         let y3_bytes = "c1a24473318c1e402fcf425258af63f41996e83df7aa8b6af37b6fe9cc8c929c";
         let y3: Scalar = y3_bytes.parse::<Scalar>().unwrap();
 
-        let mut shares = Vec::<(Scalar, Scalar)>::new();
-
-        shares.push((x1, y1));
-        shares.push((x2, y2));
-        shares.push((x3, y3));
+        let shares = vec![
+            SecretShare::from_scalars((x1, y1)).unwrap(),
+            SecretShare::from_scalars((x2, y2)).unwrap(),
+            SecretShare::from_scalars((x3, y3)).unwrap(),
+        ];
 
         let s = secret_share_combine(&shares, 3).unwrap();
 
-        println!("laooo {}", hex::encode(s.serialize().to_vec()));
+        println!("laooo {}", hex::encode(s.serialize()));
+    }
+
+    #[test]
+    fn test_deterministic_rng() {
+        let hex = "781650e9b6e646b581cff8ddb57017177d832a7f3d8086aa32117c1a91b8b5cf";
+        let secret = hex.parse::<Scalar>().unwrap();
+
+        let (secrets, _, vss_commitments) =
+            secret_share_gen_with_rng(&mut deterministic_rng([7; 32]), secret, 5, 3).unwrap();
+
+        // Pinned shares for seed 0x07...07.
+        assert_eq!(
+            hex::encode(secrets[0].secret.expose().serialize()),
+            "b1d8273dc7f1d919424ab7f8f47e29ff532579b3c37fe6717d33bbf93dd611de"
+        );
+        assert_eq!(
+            hex::encode(secrets[4].secret.expose().serialize()),
+            "0bf8cdb887ac555daaa0fff15fca72dfdbbd7925a0dba1595c53b5d75f792daf"
+        );
+
+        for secret in secrets.iter() {
+            assert!(vss_verify_secret(secret, &vss_commitments));
+        }
+
+        assert_eq!(secret_share_combine(&secrets[2..], 3).unwrap(), secret);
+
+        // Pinned DKG constant commitment for the same seed.
+        let index = ParticipantIndex::new(1).unwrap();
+        let (commitment, shares) =
+            dkg_deal_with_rng(&mut deterministic_rng([7; 32]), index, 5, 3, b"session").unwrap();
+
+        assert_eq!(
+            hex::encode(commitment.vss_commitments.constant().serialize()),
+            "0235311ed2f953d38179fab3fd84b1bab6c021c9c54f4d9c5f0772f7fedc6c6acc"
+        );
+
+        // Same seed gives the same dealing, a different seed does not.
+        let (commitment_again, shares_again) =
+            dkg_deal_with_rng(&mut deterministic_rng([7; 32]), index, 5, 3, b"session").unwrap();
+
+        assert_eq!(commitment, commitment_again);
+        assert_eq!(shares, shares_again);
+
+        let (nonce_dealing, _) =
+            nonce_deal_with_rng(&mut deterministic_rng([7; 32]), index, 0, 2, 5, 3).unwrap();
+        let (nonce_dealing_other, _) =
+            nonce_deal_with_rng(&mut deterministic_rng([8; 32]), index, 0, 2, 5, 3).unwrap();
+
+        assert_ne!(nonce_dealing, nonce_dealing_other);
+    }
+
+    #[test]
+    fn test_deterministic_rng_protocols() {
+        let hex = "781650e9b6e646b581cff8ddb57017177d832a7f3d8086aa32117c1a91b8b5cf";
+        let secret = hex.parse::<Scalar>().unwrap();
+
+        let (secrets, _, vss_commitments) =
+            secret_share_gen_with_rng(&mut deterministic_rng([7; 32]), secret, 5, 3).unwrap();
+
+        vss_verify_secrets_with_rng(&mut deterministic_rng([7; 32]), &secrets, &vss_commitments)
+            .unwrap();
+
+        // Same seed gives the same output for every randomized step.
+        let helpers: Vec<ParticipantIndex> = secrets[..3].iter().map(|share| share.index).collect();
+
+        let repair = |seed| {
+            repair_deal_with_rng(
+                &mut deterministic_rng(seed),
+                &secrets[0],
+                &helpers,
+                secrets[4].index,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(repair([7; 32]), repair([7; 32]));
+        assert_ne!(repair([7; 32]), repair([8; 32]));
+
+        let dleq = |seed| {
+            dleq_prove_with_rng(
+                &mut deterministic_rng(seed),
+                secret,
+                Point::generator(),
+                vss_commitments.constant(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(dleq([7; 32]), dleq([7; 32]));
+        assert_ne!(dleq([7; 32]), dleq([8; 32]));
+
+        let encrypt = |seed| {
+            vse_encrypt_share_with_rng(
+                &mut deterministic_rng(seed),
+                secrets[1].secret.expose(),
+                vss_commitments.constant(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(encrypt([7; 32]), encrypt([7; 32]));
+        assert_ne!(encrypt([7; 32]), encrypt([8; 32]));
+
+        let index = ParticipantIndex::new(1).unwrap();
+        let seal = |seed| {
+            transport_seal_with_rng(
+                &mut deterministic_rng(seed),
+                &[1; 32],
+                index,
+                secrets[1].index,
+                [2; 32],
+                b"share",
+            )
+            .unwrap()
+        };
+
+        assert_eq!(seal([7; 32]), seal([7; 32]));
+        assert_ne!(seal([7; 32]), seal([8; 32]));
     }
 }
@@ -0,0 +1,119 @@
+mod transport_tests {
+    use noist::{
+        error::NoistError,
+        secret::secret_share_gen,
+        share::ParticipantIndex,
+        transport::{transport_decrypt_share, transport_encrypt_share, transport_key},
+    };
+    use secp::Scalar;
+
+    #[test]
+    fn test_transport_share() -> Result<(), NoistError> {
+        let sender_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let recipient_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let session_id = rand::random::<[u8; 32]>();
+
+        // Both ends derive the same symmetric key.
+        assert_eq!(
+            transport_key(sender_secret, recipient_secret.base_point_mul()),
+            transport_key(recipient_secret, sender_secret.base_point_mul())
+        );
+
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, _, _) = secret_share_gen(secret, 3, 2)?;
+
+        let sender = ParticipantIndex::new(1)?;
+        let recipient = ParticipantIndex::new(2)?;
+
+        let package = transport_encrypt_share(
+            sender_secret,
+            recipient_secret.base_point_mul(),
+            sender,
+            session_id,
+            &secret_shares[1],
+        )?;
+
+        let secret_share = transport_decrypt_share(
+            recipient_secret,
+            sender_secret.base_point_mul(),
+            recipient,
+            session_id,
+            &package,
+        )?;
+
+        assert_eq!(secret_share, secret_shares[1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transport_share_tampered() -> Result<(), NoistError> {
+        let sender_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let recipient_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let session_id = rand::random::<[u8; 32]>();
+
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let (secret_shares, _, _) = secret_share_gen(secret, 3, 2)?;
+
+        let recipient = ParticipantIndex::new(2)?;
+
+        let package = transport_encrypt_share(
+            sender_secret,
+            recipient_secret.base_point_mul(),
+            ParticipantIndex::new(1)?,
+            session_id,
+            &secret_shares[1],
+        )?;
+
+        let decrypt = |package| {
+            transport_decrypt_share(
+                recipient_secret,
+                sender_secret.base_point_mul(),
+                recipient,
+                session_id,
+                package,
+            )
+        };
+
+        // Flipped ciphertext bit.
+        let mut tampered = package.clone();
+        tampered.ciphertext[0] ^= 1;
+        assert_eq!(decrypt(&tampered), Err(NoistError::DecryptionFailure));
+
+        // Sender is bound by the associated data.
+        let mut tampered = package.clone();
+        tampered.sender = ParticipantIndex::new(3)?;
+        assert_eq!(decrypt(&tampered), Err(NoistError::DecryptionFailure));
+
+        // Session id is bound by the associated data.
+        let mut tampered = package.clone();
+        tampered.session_id = rand::random::<[u8; 32]>();
+        assert_eq!(decrypt(&tampered), Err(NoistError::DecryptionFailure));
+
+        // Package sealed to someone else.
+        let mut tampered = package.clone();
+        tampered.recipient = ParticipantIndex::new(3)?;
+        assert_eq!(
+            decrypt(&tampered),
+            Err(NoistError::IndexMismatch {
+                expected: recipient,
+                found: ParticipantIndex::new(3)?
+            })
+        );
+
+        // Wrong key.
+        let eavesdropper_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        assert_eq!(
+            transport_decrypt_share(
+                eavesdropper_secret,
+                sender_secret.base_point_mul(),
+                recipient,
+                session_id,
+                &package,
+            ),
+            Err(NoistError::DecryptionFailure)
+        );
+
+        Ok(())
+    }
+}
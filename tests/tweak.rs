@@ -0,0 +1,145 @@
+mod common;
+
+mod tweak_tests {
+    use noist::{
+        error::NoistError,
+        schnorr::{verify_schnorr, SignFlag},
+        secret::secret_share_gen,
+        session::{
+            partial_sig_aggregate_tweaked, partial_sig_verify, partial_sign_tweaked,
+            PartialSignature,
+        },
+        tweak::{TweakMode, TweakedGroupKey},
+        vss::vss_public_share,
+    };
+    use secp::{MaybePoint, Point, Scalar};
+
+    use crate::common::nonce_slot;
+
+    fn threshold_sign_tweaked(
+        tweak: impl Fn(&TweakedGroupKey) -> Result<TweakedGroupKey, NoistError>,
+    ) -> Result<(), NoistError> {
+        let message = rand::random::<[u8; 32]>();
+
+        let secret_key = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let (secret_shares, group_key, key_vss_commitments) = secret_share_gen(secret_key, 5, 3)?;
+        let (nonces, slot_nonce) = nonce_slot(5, 3)?;
+
+        let tweaked_key = tweak(&TweakedGroupKey::new(group_key))?;
+
+        let mut partial_sigs = Vec::<PartialSignature>::new();
+
+        for index in [0, 2, 4] {
+            let partial_sig = partial_sign_tweaked(
+                &tweaked_key,
+                &secret_shares[index],
+                &nonces[index],
+                &message,
+                SignFlag::BIP0340Sign,
+            )?;
+
+            // Partial signatures verify under the output key with adjusted public shares.
+            let public_share = vss_public_share(partial_sig.index, &key_vss_commitments)?;
+
+            assert!(partial_sig_verify(
+                tweaked_key.output_key(),
+                &tweaked_key.tweak_public_share(&public_share),
+                &slot_nonce,
+                &partial_sig,
+                &message,
+                SignFlag::BIP0340Sign,
+            ));
+
+            partial_sigs.push(partial_sig);
+        }
+
+        let signature = partial_sig_aggregate_tweaked(
+            &tweaked_key,
+            &slot_nonce,
+            &partial_sigs,
+            &message,
+            3,
+            SignFlag::BIP0340Sign,
+        )?;
+
+        verify_schnorr(
+            tweaked_key.output_key().serialize_xonly(),
+            message,
+            signature,
+            SignFlag::BIP0340Sign,
+        )
+    }
+
+    #[test]
+    fn test_taproot_tweak() -> Result<(), NoistError> {
+        // BIP-341 wallet test vector with no script tree.
+        let internal_key =
+            Point::lift_x_hex("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d")
+                .unwrap();
+
+        let tweaked_key = TweakedGroupKey::new(internal_key).apply_taproot_tweak(None)?;
+
+        assert_eq!(
+            hex::encode(tweaked_key.output_key().serialize_xonly()),
+            "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_sign_taproot() -> Result<(), NoistError> {
+        let merkle_root = rand::random::<[u8; 32]>();
+
+        // Repeat to cover group keys with both odd and even y.
+        for _ in 0..4 {
+            threshold_sign_tweaked(|key| key.apply_taproot_tweak(None))?;
+            threshold_sign_tweaked(|key| key.apply_taproot_tweak(Some(merkle_root)))?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_sign_tweak_chain() -> Result<(), NoistError> {
+        for _ in 0..4 {
+            threshold_sign_tweaked(|key| {
+                key.apply_tweak(
+                    Scalar::reduce_from(&rand::random::<[u8; 32]>()),
+                    TweakMode::Plain,
+                )?
+                .apply_tweak(
+                    Scalar::reduce_from(&rand::random::<[u8; 32]>()),
+                    TweakMode::XOnly,
+                )?
+                .apply_taproot_tweak(None)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tweak_accumulation() -> Result<(), NoistError> {
+        let secret_key = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let tweak = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let tweaked_key = TweakedGroupKey::new(secret_key.base_point_mul())
+            .apply_tweak(tweak, TweakMode::XOnly)?
+            .apply_taproot_tweak(None)?;
+
+        // Output key (Q) is = g * P + tG.
+        let secret_key = match tweaked_key.is_negated() {
+            true => -secret_key,
+            false => secret_key,
+        };
+
+        assert_eq!(
+            (secret_key + tweaked_key.tweak()).base_point_mul(),
+            MaybePoint::Valid(tweaked_key.output_key())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+mod vse_tests {
+    use noist::{
+        error::NoistError,
+        vse::{dleq_prove, dleq_verify, vse_decrypt_share, vse_encrypt_share, vse_verify_share},
+    };
+    use secp::{Point, Scalar};
+
+    #[test]
+    fn test_dleq() -> Result<(), NoistError> {
+        let secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let base = Scalar::reduce_from(&rand::random::<[u8; 32]>()).base_point_mul();
+
+        let proof = dleq_prove(secret, Point::generator(), base)?;
+
+        assert!(dleq_verify(
+            Point::generator(),
+            secret.base_point_mul(),
+            base,
+            secret * base,
+            &proof
+        ));
+
+        // Different discrete logs do not verify.
+        let other = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        assert!(!dleq_verify(
+            Point::generator(),
+            secret.base_point_mul(),
+            base,
+            other * base,
+            &proof
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vse_encrypted_share() -> Result<(), NoistError> {
+        let recipient_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+        let recipient_public = recipient_secret.base_point_mul();
+
+        let share = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        let encrypted = vse_encrypt_share(share, recipient_public)?;
+
+        // Anyone can check the ciphertext against the public share.
+        assert!(vse_verify_share(&encrypted, share.base_point_mul()));
+
+        assert_eq!(vse_decrypt_share(&encrypted, recipient_secret)?, share);
+
+        // Ciphertext for a different share does not verify.
+        let other_share = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        assert!(!vse_verify_share(&encrypted, other_share.base_point_mul()));
+
+        // Someone other than the recipient cannot derive the committed pad.
+        let other_secret = Scalar::reduce_from(&rand::random::<[u8; 32]>());
+
+        assert_eq!(
+            vse_decrypt_share(&encrypted, other_secret),
+            Err(NoistError::DecryptionFailure)
+        );
+
+        // Pads are fresh per encryption.
+        let reencrypted = vse_encrypt_share(share, recipient_public)?;
+
+        assert_ne!(encrypted.ephemeral_key, reencrypted.ephemeral_key);
+        assert_ne!(encrypted.encrypted_share, reencrypted.encrypted_share);
+
+        Ok(())
+    }
+}
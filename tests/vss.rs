@@ -0,0 +1,138 @@
+mod vss_tests {
+    use noist::{
+        error::NoistError,
+        secret::{random_scalar, secret_share_gen},
+        share::{ParticipantIndex, PublicShare, SecretShare},
+        vss::{
+            multi_scalar_mul, pow_scalar, scalar_powers, vss_commit, vss_prove, vss_public_share,
+            vss_verify_points, vss_verify_proof, vss_verify_secrets, VssCommitment,
+        },
+    };
+    use secp::{MaybePoint, MaybeScalar, Point};
+
+    #[test]
+    fn test_vss_msm() -> Result<(), NoistError> {
+        let base = random_scalar()?;
+        let powers = scalar_powers(base, 6);
+
+        for (j, power) in powers.iter().enumerate() {
+            assert_eq!(*power, pow_scalar(base, j as u32));
+        }
+
+        // Multi-scalar multiplication matches the sum of individual products, skipping zeros.
+        let terms = (0..5)
+            .map(|j| {
+                let scalar = match j {
+                    2 => MaybeScalar::Zero,
+                    _ => MaybeScalar::Valid(random_scalar()?),
+                };
+
+                Ok((random_scalar()?.base_point_mul(), scalar))
+            })
+            .collect::<Result<Vec<(Point, MaybeScalar)>, NoistError>>()?;
+
+        let mut expected = MaybePoint::Infinity;
+
+        for (point, scalar) in terms.iter() {
+            expected += *scalar * *point;
+        }
+
+        assert_eq!(multi_scalar_mul(&terms), expected);
+        assert_eq!(multi_scalar_mul(&[]), MaybePoint::Infinity);
+
+        // Public share evaluated from the commitments matches the dealt share.
+        let (secret_shares, _, vss_commitments) = secret_share_gen(random_scalar()?, 7, 4)?;
+
+        for share in secret_shares.iter() {
+            assert_eq!(
+                vss_public_share(share.index, &vss_commitments)?,
+                share.public_share()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vss_verify_batch() -> Result<(), NoistError> {
+        let (mut secret_shares, _, vss_commitments) = secret_share_gen(random_scalar()?, 20, 7)?;
+
+        let public_shares: Vec<PublicShare> = secret_shares
+            .iter()
+            .map(|share| share.public_share())
+            .collect();
+
+        vss_verify_secrets(&secret_shares, &vss_commitments)?;
+        vss_verify_points(&public_shares, &vss_commitments)?;
+
+        // Empty batch is trivially valid.
+        vss_verify_secrets(&[], &vss_commitments)?;
+
+        // Tampered share is blamed by index.
+        secret_shares[13] = SecretShare::new(secret_shares[13].index, random_scalar()?);
+
+        assert_eq!(
+            vss_verify_secrets(&secret_shares, &vss_commitments),
+            Err(NoistError::VssMismatch(ParticipantIndex::new(14)?))
+        );
+
+        let mut public_shares = public_shares;
+        public_shares[4].point = random_scalar()?.base_point_mul();
+
+        assert_eq!(
+            vss_verify_points(&public_shares, &vss_commitments),
+            Err(NoistError::VssMismatch(ParticipantIndex::new(5)?))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vss_proof() -> Result<(), NoistError> {
+        let index = ParticipantIndex::new(3)?;
+        let context = b"session-1";
+
+        let secret = random_scalar()?;
+        let vss_commitments = vss_commit(&[secret, random_scalar()?])?;
+
+        let proof = vss_prove(secret, index, context)?;
+        assert!(vss_verify_proof(&vss_commitments, index, context, &proof));
+
+        // Proof is bound to the index and the session context.
+        assert!(!vss_verify_proof(
+            &vss_commitments,
+            ParticipantIndex::new(4)?,
+            context,
+            &proof
+        ));
+        assert!(!vss_verify_proof(
+            &vss_commitments,
+            index,
+            b"session-2",
+            &proof
+        ));
+
+        // Rogue commitment A_0' = A_0 - P_other cannot be proven without its discrete log.
+        let other_public = random_scalar()?.base_point_mul();
+        let rogue_constant = match vss_commitments.constant() - other_public {
+            MaybePoint::Valid(point) => point,
+            MaybePoint::Infinity => return Err(NoistError::InvalidPoint),
+        };
+        let rogue_commitments = VssCommitment::new(vec![rogue_constant])?;
+
+        assert!(!vss_verify_proof(
+            &rogue_commitments,
+            index,
+            context,
+            &proof
+        ));
+        assert!(!vss_verify_proof(
+            &rogue_commitments,
+            index,
+            context,
+            &vss_prove(random_scalar()?, index, context)?
+        ));
+
+        Ok(())
+    }
+}